@@ -0,0 +1,27 @@
+use sp_ark_models::{
+    cost::{CostModel, LinearCost},
+    metering::Operation,
+};
+
+/// [`CostModel`] of the BLS12-377 host calls, seeded from the benchmark figures of
+/// the host calls performed by a Substrate runtime published in the README.
+///
+/// The MSM costs are fitted to the 10 and 1000 arguments figures.
+/// The pairing figure is split between the Miller loop and the final exponentiation
+/// in the same proportion as for BLS12-381.
+pub struct HostCostModel;
+
+impl CostModel for HostCostModel {
+    fn cost_of(op: Operation) -> Option<LinearCost> {
+        match op {
+            Operation::MultiMillerLoop => Some(LinearCost::new(0, 1_135_265)),
+            Operation::FinalExponentiation => Some(LinearCost::new(314_255, 0)),
+            Operation::PairingCheck => Some(LinearCost::new(314_255, 1_135_265)),
+            Operation::MsmG1 => Some(LinearCost::new(620_415, 28_209)),
+            Operation::MsmG2 => Some(LinearCost::new(2_329_921, 92_192)),
+            Operation::MulProjectiveG1 => Some(LinearCost::new(65_680, 0)),
+            Operation::MulProjectiveG2 => Some(LinearCost::new(212_200, 0)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use ark_std::{marker::PhantomData, vec::Vec};
+use sp_ark_models::{batch, software};
+
+use crate::HostFunctions;
+
+/// [`HostFunctions`] adapter computing each operation with the upstream arkworks
+/// implementation whenever the wrapped host call fails.
+///
+/// With the `software` feature enabled the wrapped host calls are never made.
+pub struct Fallback<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> HostFunctions for Fallback<H> {
+    fn bls12_377_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (a.as_slice(), b.as_slice()),
+            |(a, b)| H::bls12_377_multi_miller_loop(a.to_vec(), b.to_vec()),
+            |(a, b)| software::multi_miller_loop::<ark_bls12_377::Bls12_377>(a, b),
+        )
+    }
+
+    fn bls12_377_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            f12.as_slice(),
+            |f12| H::bls12_377_final_exponentiation(f12.to_vec()),
+            software::final_exponentiation::<ark_bls12_377::Bls12_377>,
+        )
+    }
+
+    fn bls12_377_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        // Without a host pairing check, `multi_pairing_is_one` falls back to the Miller
+        // loop and final exponentiation, preferring the host to software for each.
+        if cfg!(feature = "software") {
+            return software::pairing_check::<ark_bls12_377::Bls12_377>(&a, &b);
+        }
+        H::bls12_377_pairing_check(a, b)
+    }
+
+    fn bls12_377_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_377_msm_g1(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_sw::<ark_bls12_377::g1::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_377_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_377_msm_g2(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_sw::<ark_bls12_377::g2::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_377_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bls12_377_mul_projective_g1(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_projective_sw::<ark_bls12_377::g1::Config>(base, scalar),
+        )
+    }
+
+    fn bls12_377_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bls12_377_mul_projective_g2(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_projective_sw::<ark_bls12_377::g2::Config>(base, scalar),
+        )
+    }
+
+    fn bls12_377_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bls12_377_mul_gt(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_gt::<ark_bls12_377::Bls12_377>(base, scalar),
+        )
+    }
+
+    fn bls12_377_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_377_msm_gt(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_gt::<ark_bls12_377::Bls12_377>(bases, scalars),
+        )
+    }
+
+    fn bls12_377_fft(coeffs: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (coeffs.as_slice(), offset.as_slice()),
+            |(coeffs, offset)| H::bls12_377_fft(coeffs.to_vec(), offset.to_vec()),
+            |(coeffs, offset)| software::fft::<ark_bls12_377::Fr>(coeffs, offset),
+        )
+    }
+
+    fn bls12_377_ifft(evals: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (evals.as_slice(), offset.as_slice()),
+            |(evals, offset)| H::bls12_377_ifft(evals.to_vec(), offset.to_vec()),
+            |(evals, offset)| software::ifft::<ark_bls12_377::Fr>(evals, offset),
+        )
+    }
+
+    fn bls12_377_batch_inverse(values: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            values.as_slice(),
+            |values| H::bls12_377_batch_inverse(values.to_vec()),
+            software::batch_inverse::<ark_bls12_377::Fr>,
+        )
+    }
+
+    fn bls12_377_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_377_msm_small_g1(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_small_sw::<ark_bls12_377::g1::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_377_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_377_msm_small_g2(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_small_sw::<ark_bls12_377::g2::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_377_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            calls.as_slice(),
+            |calls| H::bls12_377_batch(calls.to_vec()),
+            |calls| batch::execute(calls, crate::batch::dispatch::<Self>),
+        )
+    }
+
+    fn bls12_377_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            points.as_slice(),
+            |points| H::bls12_377_normalize_g1(points.to_vec()),
+            software::normalize_sw::<ark_bls12_377::g1::Config>,
+        )
+    }
+
+    fn bls12_377_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            points.as_slice(),
+            |points| H::bls12_377_normalize_g2(points.to_vec()),
+            software::normalize_sw::<ark_bls12_377::g2::Config>,
+        )
+    }
+
+    fn bls12_377_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            compressed.as_slice(),
+            |compressed| H::bls12_377_deserialize_g1(compressed.to_vec()),
+            software::deserialize_batch::<ark_bls12_377::G1Affine>,
+        )
+    }
+
+    fn bls12_377_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            compressed.as_slice(),
+            |compressed| H::bls12_377_deserialize_g2(compressed.to_vec()),
+            software::deserialize_batch::<ark_bls12_377::G2Affine>,
+        )
+    }
+}
+
+/// [`software::with_fallback`], never calling the host with the `software` feature
+/// of this crate enabled.
+fn with_fallback<A: Copy, R>(
+    args: A,
+    host: impl FnOnce(A) -> Result<R, ()>,
+    fallback: impl FnOnce(A) -> Result<R, ()>,
+) -> Result<R, ()> {
+    software::with_fallback(cfg!(feature = "software"), args, host, fallback)
+}
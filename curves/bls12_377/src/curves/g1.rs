@@ -5,16 +5,23 @@ use codec::{Decode, Encode};
 use core::ops::Neg;
 use sp_ark_models::{
     bls12,
+    msm::SWTryMsm,
     short_weierstrass::{Affine as SWAffine, Projective, SWCurveConfig},
+    small_msm::SmallMsm,
+    software,
     twisted_edwards::{
         Affine as TEAffine, MontCurveConfig, Projective as TEProjective, TECurveConfig,
     },
-    CurveConfig,
+    CurveConfig, HostCallError, VariableBaseMSM,
 };
 
 pub type G1Affine<H> = bls12::G1Affine<crate::curves::Config<H>>;
 pub type G1Projective<H> = bls12::G1Projective<crate::curves::Config<H>>;
 
+/// Precomputed multiples of a fixed point of G1, such as the generator, see
+/// [`sp_ark_models::fixed_base`].
+pub type FixedBase<H> = sp_ark_models::fixed_base::FixedBase<G1Projective<H>>;
+
 use crate::{ArkScale, Fq, Fr, HostFunctions};
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -32,6 +39,72 @@ impl<H: HostFunctions> CurveConfig for Config<H> {
     const COFACTOR_INV: Fr = MontFp!("5285428838741532253824584287042945485047145357130994810877");
 }
 
+impl<H: HostFunctions> Config<H> {
+    /// Fallible version of [`SWCurveConfig::msm`], surfacing host call errors.
+    pub fn try_msm(
+        bases: &[SWAffine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[SWAffine<Self>]> = bases.into();
+        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
+
+        let result = H::bls12_377_msm_g1(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`SWCurveConfig::mul_projective`], surfacing host call errors.
+    pub fn try_mul(
+        base: &Projective<Self>,
+        scalar: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result = H::bls12_377_mul_projective_g1(base.encode(), scalar.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> SWTryMsm for Config<H> {
+    fn try_msm(
+        bases: &[SWAffine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        Self::try_msm(bases, scalars)
+    }
+}
+
+impl<H: HostFunctions> SmallMsm<SWAffine<Self>> for Config<H> {
+    fn try_msm_small(
+        bases: &[SWAffine<Self>],
+        limbs: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let bases: ArkScale<&[SWAffine<Self>]> = bases.into();
+        let limbs: ArkScale<&[u64]> = limbs.into();
+
+        let result = H::bls12_377_msm_small_g1(bases.encode(), limbs.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
 impl<H: HostFunctions> SWCurveConfig for Config<H> {
     /// COEFF_A = 0
     const COEFF_A: Fq = Fq::ZERO;
@@ -51,37 +124,23 @@ impl<H: HostFunctions> SWCurveConfig for Config<H> {
         bases: &[SWAffine<Self>],
         scalars: &[<Self as CurveConfig>::ScalarField],
     ) -> Result<Projective<Self>, usize> {
-        let bases: ArkScale<&[SWAffine<Self>]> = bases.into();
-        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
-
-        let result = H::bls12_377_msm_g1(bases.encode(), scalars.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.map_err(|_| 0).map(|res| res.0)
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        // Host call errors fall back to software, `SWTryMsm::try_msm` surfaces them.
+        Ok(Self::try_msm(bases, scalars)
+            .unwrap_or_else(|_| VariableBaseMSM::msm_unchecked(bases, scalars)))
     }
 
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::bls12_377_mul_projective_g1(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(base, scalar).unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 
     fn mul_affine(base: &SWAffine<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: Projective<Self> = (*base).into();
-        let base: ArkScaleProjective<Projective<Self>> = base.into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::bls12_377_mul_projective_g1(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(&(*base).into(), scalar)
+            .unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 }
 
@@ -0,0 +1,194 @@
+use ark_std::{marker::PhantomData, vec::Vec};
+use sp_ark_models::{
+    batch::batch_len,
+    metering::{sequence_len, Meter, Meters, Operation, Usage},
+};
+
+use crate::HostFunctions;
+
+static METERS: Meters = Meters::new();
+
+fn meter<H: HostFunctions>() -> &'static Meter {
+    METERS.get::<H>()
+}
+
+/// [`HostFunctions`] wrapper recording the usage of every host call made through it.
+///
+/// The usage is recorded separately for every `H`.
+pub struct Metered<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> Metered<H> {
+    /// Usage recorded for `op`.
+    pub fn usage(op: Operation) -> Usage {
+        meter::<H>().usage(op)
+    }
+
+    /// Reset the usage recorded for every operation.
+    pub fn reset() {
+        meter::<H>().reset()
+    }
+}
+
+impl<H: HostFunctions> HostFunctions for Metered<H> {
+    fn bls12_377_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MultiMillerLoop,
+            a.len() + b.len(),
+            sequence_len(&a),
+            || H::bls12_377_multi_miller_loop(a, b),
+        )
+    }
+
+    fn bls12_377_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(Operation::FinalExponentiation, f12.len(), 1, || {
+            H::bls12_377_final_exponentiation(f12)
+        })
+    }
+
+    fn bls12_377_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        meter::<H>().call(
+            Operation::PairingCheck,
+            a.len() + b.len(),
+            sequence_len(&a),
+            || H::bls12_377_pairing_check(a, b),
+        )
+    }
+
+    fn bls12_377_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmG1,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bls12_377_msm_g1(bases, scalars),
+        )
+    }
+
+    fn bls12_377_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmG2,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bls12_377_msm_g2(bases, scalars),
+        )
+    }
+
+    fn bls12_377_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MulProjectiveG1,
+            base.len() + scalar.len(),
+            1,
+            || H::bls12_377_mul_projective_g1(base, scalar),
+        )
+    }
+
+    fn bls12_377_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MulProjectiveG2,
+            base.len() + scalar.len(),
+            1,
+            || H::bls12_377_mul_projective_g2(base, scalar),
+        )
+    }
+
+    fn bls12_377_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(Operation::MulGt, base.len() + scalar.len(), 1, || {
+            H::bls12_377_mul_gt(base, scalar)
+        })
+    }
+
+    fn bls12_377_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmGt,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bls12_377_msm_gt(bases, scalars),
+        )
+    }
+
+    fn bls12_377_fft(coeffs: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Fft,
+            coeffs.len() + offset.len(),
+            sequence_len(&coeffs),
+            || H::bls12_377_fft(coeffs, offset),
+        )
+    }
+
+    fn bls12_377_ifft(evals: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Ifft,
+            evals.len() + offset.len(),
+            sequence_len(&evals),
+            || H::bls12_377_ifft(evals, offset),
+        )
+    }
+
+    fn bls12_377_batch_inverse(values: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::BatchInverse,
+            values.len(),
+            sequence_len(&values),
+            || H::bls12_377_batch_inverse(values),
+        )
+    }
+
+    fn bls12_377_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmSmall,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bls12_377_msm_small_g1(bases, scalars),
+        )
+    }
+
+    fn bls12_377_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmSmall,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bls12_377_msm_small_g2(bases, scalars),
+        )
+    }
+
+    fn bls12_377_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(Operation::Batch, calls.len(), batch_len(&calls), || {
+            H::bls12_377_batch(calls)
+        })
+    }
+
+    fn bls12_377_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Normalize,
+            points.len(),
+            sequence_len(&points),
+            || H::bls12_377_normalize_g1(points),
+        )
+    }
+
+    fn bls12_377_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Normalize,
+            points.len(),
+            sequence_len(&points),
+            || H::bls12_377_normalize_g2(points),
+        )
+    }
+
+    fn bls12_377_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Deserialize,
+            compressed.len(),
+            sequence_len(&compressed),
+            || H::bls12_377_deserialize_g1(compressed),
+        )
+    }
+
+    fn bls12_377_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Deserialize,
+            compressed.len(),
+            sequence_len(&compressed),
+            || H::bls12_377_deserialize_g2(compressed),
+        )
+    }
+}
@@ -1,7 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use crate::{
-    Bls12_377 as Bls12_377Host, G1Projective as G1ProjectiveHost, G2Projective as G2ProjectiveHost,
-    HostFunctions,
+    Bls12_377 as Bls12_377Host, Fallback, G1Projective as G1ProjectiveHost,
+    G2Projective as G2ProjectiveHost, HostFunctions,
 };
 use ark_algebra_test_templates::*;
 
@@ -37,3 +37,54 @@ test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bls12_377>; msm);
 test_pairing!(pairing; super::Bls12_377);
+
+struct Unavailable;
+
+impl HostFunctions for Unavailable {
+    fn bls12_377_multi_miller_loop(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_377_final_exponentiation(_: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_377_msm_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_377_msm_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_377_mul_projective_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_377_mul_projective_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+type Bls12_377Fallback = Bls12_377Host<Fallback<Unavailable>>;
+type G1ProjectiveFallback = G1ProjectiveHost<Fallback<Unavailable>>;
+type G2ProjectiveFallback = G2ProjectiveHost<Fallback<Unavailable>>;
+
+test_group!(g1_fallback; G1ProjectiveFallback; sw);
+test_group!(g2_fallback; G2ProjectiveFallback; sw);
+test_pairing!(pairing_fallback; super::Bls12_377Fallback);
+
+#[test]
+fn test_fallback_matches_host() {
+    use ark_std::UniformRand;
+    use sp_ark_models::{pairing::Pairing, AffineRepr, CurveGroup, Group};
+
+    let mut rng = ark_std::test_rng();
+    let s = <Bls12_377 as Pairing>::ScalarField::rand(&mut rng);
+
+    let p = (G1Projective::generator() * s).into_affine();
+    let q = (G2Projective::generator() * s).into_affine();
+    let p_fallback = (G1ProjectiveFallback::generator() * s).into_affine();
+    let q_fallback = (G2ProjectiveFallback::generator() * s).into_affine();
+    assert_eq!(p.xy(), p_fallback.xy());
+    assert_eq!(q.xy(), q_fallback.xy());
+
+    let expected = Bls12_377::pairing(p, q);
+    let result = Bls12_377Fallback::pairing(p_fallback, q_fallback);
+    assert_eq!(expected.0, result.0);
+}
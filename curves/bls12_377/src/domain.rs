@@ -0,0 +1,15 @@
+//! Radix-2 evaluation domains over the scalar field computing their FFTs with the
+//! host, see [`sp_ark_models::domain`].
+
+use sp_ark_models::domain;
+
+use crate::{Config, Fr};
+
+/// Radix-2 evaluation domain over the scalar field.
+pub type Domain<H> = domain::Domain<Fr, Config<H>>;
+
+/// Invert every nonzero element of `values` with the host, falling back to
+/// software when it fails.
+pub fn batch_inverse<H: crate::HostFunctions>(values: &mut [Fr]) {
+    domain::batch_inverse::<Fr, Config<H>>(values)
+}
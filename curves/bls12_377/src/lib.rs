@@ -26,7 +26,9 @@
 //! * G2 curve equation: y^2 = x^3 + B, where
 //!    * B = Fq2(0, 155198655607781456406391640216936120121836107652948796323930557600032281009004493664981332883744016074664192874906)
 
+pub mod batch;
 pub mod curves;
+pub mod domain;
 
 #[cfg(feature = "r1cs")]
 pub use ark_bls12_377::constraints::*;
@@ -0,0 +1,302 @@
+//! BLS signatures over BLS12-381, following the proof of possession ciphersuites
+//! of the [IETF BLS signature draft](https://datatracker.ietf.org/doc/draft-irtf-cfrg-bls-signature/).
+//!
+//! [`min_pk`] has the public keys in G1 and the signatures in G2, while [`min_sig`]
+//! has the public keys in G2 and the signatures in G1. Hashing to the curve and
+//! verification go through the host functions.
+
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_serialize::{CanonicalSerialize, SerializationError};
+use ark_std::{iter, vec::Vec};
+use sha2::{Digest, Sha256};
+use sp_ark_models::{
+    deserialize::{CompressedPoints, DeserializeBatch},
+    AffineRepr, HostCallError,
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Bls12_381, Fr, G1Affine, G2Affine, HostFunctions};
+
+#[cfg(test)]
+mod tests;
+
+/// Secret key of both variants, zeroized when dropped.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct SecretKey(Fr);
+
+impl SecretKey {
+    /// Derive a secret key from at least 32 bytes of keying material `ikm` with `KeyGen`.
+    pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Option<Self> {
+        if ikm.len() < 32 {
+            return None;
+        }
+        let mut salt = Sha256::digest(b"BLS-SIG-KEYGEN-SALT-");
+        loop {
+            let prk = hmac_sha256(&salt, &[ikm, &[0]]);
+            let t1 = hmac_sha256(&prk, &[key_info, &[0, 48], &[1]]);
+            let t2 = hmac_sha256(&prk, &[&t1, key_info, &[0, 48], &[2]]);
+            let sk = Fr::from_be_bytes_mod_order(&[&t1[..], &t2[..16]].concat());
+            if !sk.is_zero() {
+                return Some(SecretKey(sk));
+            }
+            salt = Sha256::digest(salt);
+        }
+    }
+
+    /// Big endian encoding of the secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        bytes.copy_from_slice(&self.0.into_bigint().to_bytes_be());
+        bytes
+    }
+
+    /// Decode a big endian encoded secret key, `None` if it is zero or not reduced.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let sk = Fr::from_be_bytes_mod_order(bytes);
+        (!sk.is_zero() && sk.into_bigint().to_bytes_be() == bytes).then_some(SecretKey(sk))
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > block.len() {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    for chunk in data {
+        inner.update(chunk);
+    }
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+fn compressed<P: CanonicalSerialize>(point: &P) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(point.compressed_size());
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("serializing to a vector never fails");
+    bytes
+}
+
+fn deserialize_set<P: AffineRepr>(
+    bytes: &[u8],
+    deserialize_batch: impl FnOnce(&[u8]) -> Result<Vec<P>, SerializationError>,
+) -> Result<Vec<P>, SerializationError> {
+    let points = bytes.chunks_exact(P::generator().compressed_size());
+    if !points.remainder().is_empty() {
+        return Err(SerializationError::InvalidData);
+    }
+    let mut compressed = CompressedPoints::<P>::new();
+    compressed.read(bytes, points.len() as u64)?;
+    deserialize_batch(&compressed.encode())
+}
+
+fn sum<P: AffineRepr>(points: &[P]) -> Option<P> {
+    let (first, rest) = points.split_first()?;
+    Some(
+        rest.iter()
+            .fold(first.into_group(), |acc, p| acc + p)
+            .into(),
+    )
+}
+
+fn min_pk_pairing_check<H: HostFunctions>(
+    pks: Vec<G1Affine<H>>,
+    hashes: Vec<G2Affine<H>>,
+    sig: G2Affine<H>,
+) -> bool {
+    Bls12_381::<H>::multi_pairing_is_one(
+        pks.into_iter()
+            .chain(iter::once(-G1Affine::<H>::generator())),
+        hashes.into_iter().chain(iter::once(sig)),
+    )
+}
+
+fn min_sig_pairing_check<H: HostFunctions>(
+    pks: Vec<G2Affine<H>>,
+    hashes: Vec<G1Affine<H>>,
+    sig: G1Affine<H>,
+) -> bool {
+    Bls12_381::<H>::multi_pairing_is_one(
+        hashes.into_iter().chain(iter::once(sig)),
+        pks.into_iter()
+            .chain(iter::once(-G2Affine::<H>::generator())),
+    )
+}
+
+macro_rules! bls_variant {
+    (
+        $pk:ident,
+        $sig:ident,
+        $hash_to_curve:ident,
+        $pairing_check:ident,
+        $deserialize_batch_pk:ident,
+        $deserialize_batch_sig:ident,
+        $ciphersuite:literal,
+        $pop_ciphersuite:literal
+    ) => {
+        use super::*;
+
+        /// Ciphersuite of the signatures, used as domain separation tag.
+        pub const CIPHERSUITE: &[u8] = $ciphersuite;
+
+        /// Ciphersuite of the proofs of possession, used as domain separation tag.
+        pub const POP_CIPHERSUITE: &[u8] = $pop_ciphersuite;
+
+        pub type PublicKey<H> = $pk<H>;
+        pub type Signature<H> = $sig<H>;
+
+        /// Public key of `sk`.
+        pub fn sk_to_pk<H: HostFunctions>(sk: &SecretKey) -> PublicKey<H> {
+            (PublicKey::<H>::generator() * sk.0).into()
+        }
+
+        /// Check that `pk` is a valid public key, i.e. a non identity point of the prime
+        /// order subgroup.
+        pub fn key_validate<H: HostFunctions>(pk: &PublicKey<H>) -> bool {
+            !pk.is_zero() && pk.is_on_curve() && pk.is_in_correct_subgroup_assuming_on_curve()
+        }
+
+        /// Deserialize the concatenated compressed public keys `bytes`, decompressing
+        /// and checking them all at once, and rejecting the identity as
+        /// [`key_validate`] does.
+        pub fn deserialize_public_keys<H: HostFunctions>(
+            bytes: &[u8],
+        ) -> Result<Vec<PublicKey<H>>, SerializationError> {
+            let pks = deserialize_set(bytes, Bls12_381::<H>::$deserialize_batch_pk)?;
+            if pks.iter().any(|pk| pk.is_zero()) {
+                return Err(SerializationError::InvalidData);
+            }
+            Ok(pks)
+        }
+
+        /// Deserialize the concatenated compressed signatures `bytes`, decompressing
+        /// and checking them all at once.
+        pub fn deserialize_signatures<H: HostFunctions>(
+            bytes: &[u8],
+        ) -> Result<Vec<Signature<H>>, SerializationError> {
+            deserialize_set(bytes, Bls12_381::<H>::$deserialize_batch_sig)
+        }
+
+        /// Sign `msg` with `sk`.
+        pub fn sign<H: HostFunctions>(
+            sk: &SecretKey,
+            msg: &[u8],
+        ) -> Result<Signature<H>, HostCallError> {
+            core_sign(sk, msg, CIPHERSUITE)
+        }
+
+        /// Verify the signature `sig` of `msg` by `pk`.
+        pub fn verify<H: HostFunctions>(pk: &PublicKey<H>, msg: &[u8], sig: &Signature<H>) -> bool {
+            core_aggregate_verify(&[*pk], &[msg], sig, CIPHERSUITE)
+        }
+
+        /// Aggregate `sigs` into a single signature, `None` if there are no signatures.
+        pub fn aggregate<H: HostFunctions>(sigs: &[Signature<H>]) -> Option<Signature<H>> {
+            sum(sigs)
+        }
+
+        /// Verify the aggregate signature `sig` of each message of `msgs` by the
+        /// public key at the same position in `pks`.
+        pub fn aggregate_verify<H: HostFunctions>(
+            pks: &[PublicKey<H>],
+            msgs: &[&[u8]],
+            sig: &Signature<H>,
+        ) -> bool {
+            core_aggregate_verify(pks, msgs, sig, CIPHERSUITE)
+        }
+
+        /// Verify the aggregate signature `sig` of `msg` by all of `pks`, whose
+        /// possession must have been proven with [`pop_verify`].
+        pub fn fast_aggregate_verify<H: HostFunctions>(
+            pks: &[PublicKey<H>],
+            msg: &[u8],
+            sig: &Signature<H>,
+        ) -> bool {
+            match sum(pks) {
+                Some(pk) => verify(&pk, msg, sig),
+                None => false,
+            }
+        }
+
+        /// Prove the possession of `sk`.
+        pub fn pop_prove<H: HostFunctions>(sk: &SecretKey) -> Result<Signature<H>, HostCallError> {
+            core_sign(sk, &compressed(&sk_to_pk::<H>(sk)), POP_CIPHERSUITE)
+        }
+
+        /// Verify the proof of possession `proof` of the secret key of `pk`.
+        pub fn pop_verify<H: HostFunctions>(pk: &PublicKey<H>, proof: &Signature<H>) -> bool {
+            core_aggregate_verify(&[*pk], &[&compressed(pk)], proof, POP_CIPHERSUITE)
+        }
+
+        fn core_sign<H: HostFunctions>(
+            sk: &SecretKey,
+            msg: &[u8],
+            dst: &[u8],
+        ) -> Result<Signature<H>, HostCallError> {
+            Ok(($hash_to_curve::<H>(msg, dst)? * sk.0).into())
+        }
+
+        fn core_aggregate_verify<H: HostFunctions>(
+            pks: &[PublicKey<H>],
+            msgs: &[&[u8]],
+            sig: &Signature<H>,
+            dst: &[u8],
+        ) -> bool {
+            if pks.is_empty() || pks.len() != msgs.len() {
+                return false;
+            }
+            if !sig.is_on_curve() || !sig.is_in_correct_subgroup_assuming_on_curve() {
+                return false;
+            }
+            if !pks.iter().all(key_validate) {
+                return false;
+            }
+            match msgs
+                .iter()
+                .map(|msg| $hash_to_curve::<H>(msg, dst))
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(hashes) => $pairing_check(pks.to_vec(), hashes, *sig),
+                Err(_) => false,
+            }
+        }
+    };
+}
+
+/// Minimal public key size variant, with public keys in G1 and signatures in G2.
+pub mod min_pk {
+    use crate::hash_to_g2;
+
+    bls_variant!(
+        G1Affine,
+        G2Affine,
+        hash_to_g2,
+        min_pk_pairing_check,
+        deserialize_g1_batch,
+        deserialize_g2_batch,
+        b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_",
+        b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_"
+    );
+}
+
+/// Minimal signature size variant, with public keys in G2 and signatures in G1.
+pub mod min_sig {
+    use crate::hash_to_g1;
+
+    bls_variant!(
+        G2Affine,
+        G1Affine,
+        hash_to_g1,
+        min_sig_pairing_check,
+        deserialize_g2_batch,
+        deserialize_g1_batch,
+        b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_",
+        b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_"
+    );
+}
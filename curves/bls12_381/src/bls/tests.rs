@@ -0,0 +1,186 @@
+use ark_std::vec::Vec;
+
+use super::{min_pk, min_sig, SecretKey};
+use crate::{curves::tests::Host, Fallback};
+
+// Hashing to the curve and the pairing check are computed in software.
+type H = Fallback<Host>;
+
+fn secret_keys(n: u8) -> Vec<SecretKey> {
+    (0..n)
+        .map(|i| SecretKey::key_gen(&[i; 32], b"").unwrap())
+        .collect()
+}
+
+#[test]
+fn key_gen() {
+    assert!(SecretKey::key_gen(&[0; 31], b"").is_none());
+
+    // EIP-2333 test case 0, whose master key derivation is `KeyGen` with an empty `key_info`.
+    let seed = hex_literal::<64>(
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    );
+    let sk = SecretKey::key_gen(&seed, b"").unwrap();
+    assert_eq!(
+        sk.0,
+        ark_ff::MontFp!(
+            "6083874454709270928345386274498605044986640685124978867557563392430687146096"
+        )
+    );
+    assert_eq!(
+        SecretKey::from_bytes(&sk.to_bytes()).unwrap().to_bytes(),
+        sk.to_bytes()
+    );
+}
+
+#[test]
+fn secret_key_zeroize() {
+    use zeroize::Zeroize;
+
+    let mut sk = SecretKey::key_gen(&[1; 32], b"").unwrap();
+    sk.zeroize();
+    assert_eq!(sk.to_bytes(), [0; 32]);
+}
+
+#[test]
+fn min_pk_sign_and_verify() {
+    let sks = secret_keys(3);
+    let pks = sks.iter().map(min_pk::sk_to_pk::<H>).collect::<Vec<_>>();
+    let msgs: [&[u8]; 3] = [b"a", b"b", b"c"];
+
+    let sig = min_pk::sign::<H>(&sks[0], msgs[0]).unwrap();
+    assert!(min_pk::verify(&pks[0], msgs[0], &sig));
+    assert!(!min_pk::verify(&pks[1], msgs[0], &sig));
+    assert!(!min_pk::verify(&pks[0], msgs[1], &sig));
+
+    let sigs = sks
+        .iter()
+        .zip(msgs)
+        .map(|(sk, msg)| min_pk::sign::<H>(sk, msg).unwrap())
+        .collect::<Vec<_>>();
+    let sig = min_pk::aggregate(&sigs).unwrap();
+    assert!(min_pk::aggregate_verify(&pks, &msgs, &sig));
+    assert!(!min_pk::aggregate_verify(&pks[1..], &msgs[1..], &sig));
+
+    let sigs = sks
+        .iter()
+        .map(|sk| min_pk::sign::<H>(sk, msgs[0]).unwrap())
+        .collect::<Vec<_>>();
+    let sig = min_pk::aggregate(&sigs).unwrap();
+    assert!(min_pk::fast_aggregate_verify(&pks, msgs[0], &sig));
+    assert!(!min_pk::fast_aggregate_verify(&pks, msgs[1], &sig));
+    assert!(!min_pk::fast_aggregate_verify(&[], msgs[0], &sig));
+
+    let proof = min_pk::pop_prove::<H>(&sks[0]).unwrap();
+    assert!(min_pk::pop_verify(&pks[0], &proof));
+    assert!(!min_pk::pop_verify(&pks[1], &proof));
+}
+
+#[test]
+fn min_sig_sign_and_verify() {
+    let sks = secret_keys(3);
+    let pks = sks.iter().map(min_sig::sk_to_pk::<H>).collect::<Vec<_>>();
+    let msgs: [&[u8]; 3] = [b"a", b"b", b"c"];
+
+    let sig = min_sig::sign::<H>(&sks[0], msgs[0]).unwrap();
+    assert!(min_sig::verify(&pks[0], msgs[0], &sig));
+    assert!(!min_sig::verify(&pks[1], msgs[0], &sig));
+
+    let sigs = sks
+        .iter()
+        .zip(msgs)
+        .map(|(sk, msg)| min_sig::sign::<H>(sk, msg).unwrap())
+        .collect::<Vec<_>>();
+    let sig = min_sig::aggregate(&sigs).unwrap();
+    assert!(min_sig::aggregate_verify(&pks, &msgs, &sig));
+
+    let sigs = sks
+        .iter()
+        .map(|sk| min_sig::sign::<H>(sk, msgs[0]).unwrap())
+        .collect::<Vec<_>>();
+    let sig = min_sig::aggregate(&sigs).unwrap();
+    assert!(min_sig::fast_aggregate_verify(&pks, msgs[0], &sig));
+
+    let proof = min_sig::pop_prove::<H>(&sks[0]).unwrap();
+    assert!(min_sig::pop_verify(&pks[0], &proof));
+    assert!(!min_sig::pop_verify(&pks[1], &proof));
+}
+
+#[test]
+fn deserialize_signature_sets() {
+    use ark_serialize::CanonicalSerialize;
+    use sp_ark_models::AffineRepr;
+
+    use crate::{Fq, G1Affine};
+
+    fn concat<P: CanonicalSerialize>(points: &[P]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for p in points {
+            p.serialize_compressed(&mut bytes).unwrap();
+        }
+        bytes
+    }
+
+    let sks = secret_keys(3);
+    let pks = sks.iter().map(min_pk::sk_to_pk::<H>).collect::<Vec<_>>();
+    let sigs = sks
+        .iter()
+        .map(|sk| min_pk::sign::<H>(sk, b"a").unwrap())
+        .collect::<Vec<_>>();
+    let bytes = concat(&pks);
+    assert_eq!(
+        min_pk::deserialize_public_keys::<H>(&bytes).unwrap(),
+        pks.clone()
+    );
+    assert_eq!(
+        min_pk::deserialize_signatures::<H>(&concat(&sigs)).unwrap(),
+        sigs
+    );
+    assert!(min_pk::deserialize_public_keys::<H>(&[])
+        .unwrap()
+        .is_empty());
+    assert!(min_pk::deserialize_public_keys::<H>(&bytes[1..]).is_err());
+    assert!(min_pk::deserialize_public_keys::<H>(&concat(&[pks[0], G1Affine::zero()])).is_err());
+    assert_eq!(
+        min_sig::deserialize_signatures::<H>(&concat(&[G1Affine::<H>::zero()])).unwrap(),
+        vec![G1Affine::zero()]
+    );
+
+    // A point of the curve outside of the prime order subgroup.
+    let outside = (1u64..)
+        .filter_map(|x| G1Affine::<H>::get_point_from_x_unchecked(Fq::from(x), false))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    assert!(min_pk::deserialize_public_keys::<H>(&concat(&[pks[1], outside])).is_err());
+
+    let pks = sks.iter().map(min_sig::sk_to_pk::<H>).collect::<Vec<_>>();
+    assert_eq!(
+        min_sig::deserialize_public_keys::<H>(&concat(&pks)).unwrap(),
+        pks
+    );
+}
+
+#[test]
+fn min_pk_matches_ethereum_signature() {
+    // Signature of the all zero 32 bytes message from the Ethereum consensus BLS test vectors.
+    let sk = SecretKey::from_bytes(&hex_literal(
+        "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+    ))
+    .unwrap();
+    let sig = min_pk::sign::<H>(&sk, &[0; 32]).unwrap();
+
+    let mut bytes = Vec::new();
+    ark_serialize::CanonicalSerialize::serialize_compressed(&sig, &mut bytes).unwrap();
+    assert_eq!(
+        bytes,
+        hex_literal::<96>(
+            "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"
+        )
+    );
+}
+
+fn hex_literal<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0; N];
+    hex::decode_to_slice(hex, &mut bytes).unwrap();
+    bytes
+}
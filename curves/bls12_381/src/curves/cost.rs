@@ -0,0 +1,27 @@
+use sp_ark_models::{
+    cost::{CostModel, LinearCost},
+    metering::Operation,
+};
+
+/// [`CostModel`] of the BLS12-381 host calls, seeded from the benchmark figures of
+/// the host calls performed by a Substrate runtime published in the README.
+///
+/// The MSM costs are fitted to the 10 and 1000 arguments figures.
+/// The pairing figure is split between the Miller loop and the final exponentiation
+/// using the Groth16 verification figure, which performs a three pairs Miller loop.
+pub struct HostCostModel;
+
+impl CostModel for HostCostModel {
+    fn cost_of(op: Operation) -> Option<LinearCost> {
+        match op {
+            Operation::MultiMillerLoop => Some(LinearCost::new(0, 1_089_275)),
+            Operation::FinalExponentiation => Some(LinearCost::new(301_525, 0)),
+            Operation::PairingCheck => Some(LinearCost::new(301_525, 1_089_275)),
+            Operation::MsmG1 => Some(LinearCost::new(654_543, 29_504)),
+            Operation::MsmG2 => Some(LinearCost::new(1_881_124, 98_921)),
+            Operation::MulProjectiveG1 => Some(LinearCost::new(75_010, 0)),
+            Operation::MulProjectiveG2 => Some(LinearCost::new(210_500, 0)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use ark_ff::PrimeField;
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_std::{marker::PhantomData, vec::Vec};
+use codec::{Decode, Encode};
+use sp_ark_models::{batch, software};
+
+use crate::{g1, ArkScale, Fr, G1Projective, HostFunctions};
+
+/// [`HostFunctions`] adapter computing each operation with the upstream arkworks
+/// implementation whenever the wrapped host call fails.
+///
+/// With the `software` feature enabled the wrapped host calls are never made.
+pub struct Fallback<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> HostFunctions for Fallback<H> {
+    fn bls12_381_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (a.as_slice(), b.as_slice()),
+            |(a, b)| H::bls12_381_multi_miller_loop(a.to_vec(), b.to_vec()),
+            |(a, b)| software::multi_miller_loop::<ark_bls12_381::Bls12_381>(a, b),
+        )
+    }
+
+    fn bls12_381_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            f12.as_slice(),
+            |f12| H::bls12_381_final_exponentiation(f12.to_vec()),
+            software::final_exponentiation::<ark_bls12_381::Bls12_381>,
+        )
+    }
+
+    fn bls12_381_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        // Without a host pairing check, `multi_pairing_is_one` falls back to the Miller
+        // loop and final exponentiation, preferring the host to software for each.
+        if cfg!(feature = "software") {
+            return software::pairing_check::<ark_bls12_381::Bls12_381>(&a, &b);
+        }
+        H::bls12_381_pairing_check(a, b)
+    }
+
+    fn bls12_381_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_381_msm_g1(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_sw::<ark_bls12_381::g1::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_381_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_381_msm_g2(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_sw::<ark_bls12_381::g2::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_381_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bls12_381_mul_projective_g1(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| glv_mul_projective_g1::<H>(base, scalar),
+        )
+    }
+
+    fn bls12_381_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bls12_381_mul_projective_g2(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_projective_sw::<ark_bls12_381::g2::Config>(base, scalar),
+        )
+    }
+
+    fn bls12_381_hash_to_g1(msg: Vec<u8>, dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (msg.as_slice(), dst.as_slice()),
+            |(msg, dst)| H::bls12_381_hash_to_g1(msg.to_vec(), dst.to_vec()),
+            |(msg, dst)| software::hash_to_curve_sw::<ark_bls12_381::g1::Config>(msg, dst),
+        )
+    }
+
+    fn bls12_381_hash_to_g2(msg: Vec<u8>, dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (msg.as_slice(), dst.as_slice()),
+            |(msg, dst)| H::bls12_381_hash_to_g2(msg.to_vec(), dst.to_vec()),
+            |(msg, dst)| software::hash_to_curve_sw::<ark_bls12_381::g2::Config>(msg, dst),
+        )
+    }
+
+    fn bls12_381_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bls12_381_mul_gt(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_gt::<ark_bls12_381::Bls12_381>(base, scalar),
+        )
+    }
+
+    fn bls12_381_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_381_msm_gt(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_gt::<ark_bls12_381::Bls12_381>(bases, scalars),
+        )
+    }
+
+    fn bls12_381_register_bases_g1(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        // Software keeps no registry, leaving the bases to plain MSMs.
+        if cfg!(feature = "software") {
+            return Err(());
+        }
+        H::bls12_381_register_bases_g1(bases)
+    }
+
+    fn bls12_381_msm_with_handle_g1(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        if cfg!(feature = "software") {
+            return Err(());
+        }
+        H::bls12_381_msm_with_handle_g1(handle, scalars)
+    }
+
+    fn bls12_381_fft(coeffs: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (coeffs.as_slice(), offset.as_slice()),
+            |(coeffs, offset)| H::bls12_381_fft(coeffs.to_vec(), offset.to_vec()),
+            |(coeffs, offset)| software::fft::<ark_bls12_381::Fr>(coeffs, offset),
+        )
+    }
+
+    fn bls12_381_ifft(evals: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (evals.as_slice(), offset.as_slice()),
+            |(evals, offset)| H::bls12_381_ifft(evals.to_vec(), offset.to_vec()),
+            |(evals, offset)| software::ifft::<ark_bls12_381::Fr>(evals, offset),
+        )
+    }
+
+    fn bls12_381_batch_inverse(values: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            values.as_slice(),
+            |values| H::bls12_381_batch_inverse(values.to_vec()),
+            software::batch_inverse::<ark_bls12_381::Fr>,
+        )
+    }
+
+    fn bls12_381_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_381_msm_small_g1(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_small_sw::<ark_bls12_381::g1::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_381_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bls12_381_msm_small_g2(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_small_sw::<ark_bls12_381::g2::Config>(bases, scalars),
+        )
+    }
+
+    fn bls12_381_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            calls.as_slice(),
+            |calls| H::bls12_381_batch(calls.to_vec()),
+            |calls| batch::execute(calls, crate::batch::dispatch::<Self>),
+        )
+    }
+
+    fn bls12_381_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            points.as_slice(),
+            |points| H::bls12_381_normalize_g1(points.to_vec()),
+            software::normalize_sw::<ark_bls12_381::g1::Config>,
+        )
+    }
+
+    fn bls12_381_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            points.as_slice(),
+            |points| H::bls12_381_normalize_g2(points.to_vec()),
+            software::normalize_sw::<ark_bls12_381::g2::Config>,
+        )
+    }
+
+    fn bls12_381_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            compressed.as_slice(),
+            |compressed| H::bls12_381_deserialize_g1(compressed.to_vec()),
+            software::deserialize_batch::<ark_bls12_381::G1Affine>,
+        )
+    }
+
+    fn bls12_381_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            compressed.as_slice(),
+            |compressed| H::bls12_381_deserialize_g2(compressed.to_vec()),
+            software::deserialize_batch::<ark_bls12_381::G2Affine>,
+        )
+    }
+}
+
+/// [`software::with_fallback`], never calling the host with the `software` feature
+/// of this crate enabled.
+fn with_fallback<A: Copy, R>(
+    args: A,
+    host: impl FnOnce(A) -> Result<R, ()>,
+    fallback: impl FnOnce(A) -> Result<R, ()>,
+) -> Result<R, ()> {
+    software::with_fallback(cfg!(feature = "software"), args, host, fallback)
+}
+
+/// [`software::mul_projective_sw`] on G1 with [`g1::Config::glv_mul`].
+///
+/// The base is trusted to be in the prime order subgroup, as are the points of
+/// validated deserializations, and the scalar is reduced modulo its order.
+fn glv_mul_projective_g1<H: HostFunctions>(base: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ()> {
+    let base = ArkScaleProjective::<G1Projective<H>>::decode(&mut &base[..]).map_err(|_| ())?;
+    let scalar = ArkScale::<Vec<u64>>::decode(&mut &scalar[..]).map_err(|_| ())?;
+    let bytes = scalar
+        .0
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect::<Vec<_>>();
+
+    let result = g1::Config::<H>::glv_mul(&base.0, &Fr::from_le_bytes_mod_order(&bytes));
+
+    let result: ArkScaleProjective<G1Projective<H>> = result.into();
+    Ok(result.encode())
+}
@@ -5,8 +5,12 @@ use codec::{Decode, Encode};
 use sp_ark_models::{
     bls12,
     bls12::Bls12Config,
+    glv::{self, GlvConfig},
+    msm::SWTryMsm,
+    registry::{BasesRegistry, Handle},
     short_weierstrass::{Affine, Projective, SWCurveConfig},
-    AffineRepr, CurveConfig, Group,
+    small_msm::SmallMsm,
+    software, AffineRepr, CurveConfig, CurveGroup, Group, HostCallError, VariableBaseMSM,
 };
 
 use crate::util::{
@@ -18,6 +22,10 @@ use ark_bls12_381::{fr::Fr, Fq};
 pub type G1Affine<H> = bls12::G1Affine<crate::Config<H>>;
 pub type G1Projective<H> = bls12::G1Projective<crate::Config<H>>;
 
+/// Precomputed multiples of a fixed point of G1, such as the generator, see
+/// [`sp_ark_models::fixed_base`].
+pub type FixedBase<H> = sp_ark_models::fixed_base::FixedBase<G1Projective<H>>;
+
 #[derive(Clone, Default, PartialEq, Eq)]
 
 pub struct Config<H: HostFunctions>(PhantomData<fn() -> H>);
@@ -35,6 +43,118 @@ impl<H: HostFunctions> CurveConfig for Config<H> {
         MontFp!("52435875175126190458656871551744051925719901746859129887267498875565241663483");
 }
 
+impl<H: HostFunctions> Config<H> {
+    /// Fallible version of [`SWCurveConfig::msm`], surfacing host call errors.
+    pub fn try_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
+
+        let result = H::bls12_381_msm_g1(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`SWCurveConfig::mul_projective`], surfacing host call errors.
+    pub fn try_mul(
+        base: &Projective<Self>,
+        scalar: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result = H::bls12_381_mul_projective_g1(base.encode(), scalar.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> Config<H> {
+    /// Multiply `base`, which must be in the prime order subgroup, by `scalar` in
+    /// software with the GLV method, using [`endomorphism`].
+    pub fn glv_mul(base: &Projective<Self>, scalar: &Fr) -> Projective<Self> {
+        let endo = endomorphism(&base.into_affine()).into_group();
+        glv::mul(base, &endo, scalar)
+    }
+}
+
+impl<H: HostFunctions> GlvConfig for Config<H> {
+    /// LAMBDA = -X^2, the eigenvalue of [`endomorphism`]
+    const LAMBDA: Fr =
+        MontFp!("52435875175126190479447740508185965837461563690374988244538805122978187051009");
+
+    /// BASIS = (-1, X^2 - 1), (X^2, 1)
+    const BASIS: [(bool, u128); 4] = [
+        (true, 1),
+        (false, 228988810152649578064853576960394133503),
+        (false, 228988810152649578064853576960394133504),
+        (false, 1),
+    ];
+}
+
+impl<H: HostFunctions> BasesRegistry<Projective<Self>> for Config<H> {
+    fn register_bases(bases: &[Affine<Self>]) -> Result<Handle, HostCallError> {
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+
+        let result = H::bls12_381_register_bases_g1(bases.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        Handle::try_from(result.as_slice()).map_err(|_| HostCallError::DecodeFailed)
+    }
+
+    fn msm_with_handle(handle: &Handle, scalars: &[Fr]) -> Result<Projective<Self>, HostCallError> {
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+
+        let result = H::bls12_381_msm_with_handle_g1(handle.to_vec(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> SWTryMsm for Config<H> {
+    fn try_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        Self::try_msm(bases, scalars)
+    }
+}
+
+impl<H: HostFunctions> SmallMsm<Affine<Self>> for Config<H> {
+    fn try_msm_small(
+        bases: &[Affine<Self>],
+        limbs: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let limbs: ArkScale<&[u64]> = limbs.into();
+
+        let result = H::bls12_381_msm_small_g1(bases.encode(), limbs.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
 impl<H: HostFunctions> SWCurveConfig for Config<H> {
     /// COEFF_A = 0
     const COEFF_A: Fq = Fq::ZERO;
@@ -144,37 +264,23 @@ impl<H: HostFunctions> SWCurveConfig for Config<H> {
         bases: &[Affine<Self>],
         scalars: &[<Self as CurveConfig>::ScalarField],
     ) -> Result<Projective<Self>, usize> {
-        let bases: ArkScale<&[Affine<Self>]> = bases.into();
-        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
-
-        let result = H::bls12_381_msm_g1(bases.encode(), scalars.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.map_err(|_| 0).map(|res| res.0)
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        // Host call errors fall back to software, `SWTryMsm::try_msm` surfaces them.
+        Ok(Self::try_msm(bases, scalars)
+            .unwrap_or_else(|_| VariableBaseMSM::msm_unchecked(bases, scalars)))
     }
 
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::bls12_381_mul_projective_g1(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(base, scalar).unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 
     fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: Projective<Self> = (*base).into();
-        let base: ArkScaleProjective<Projective<Self>> = base.into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::bls12_381_mul_projective_g1(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(&(*base).into(), scalar)
+            .unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 }
 
@@ -0,0 +1,32 @@
+use codec::Decode;
+use sp_ark_models::HostCallError;
+
+use crate::{ArkScale, G1Affine, G2Affine, HostFunctions};
+
+/// RFC 9380 hash to curve suite for G1, to be used in the domain separation tag.
+pub const G1_SUITE: &[u8] = b"BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// RFC 9380 hash to curve suite for G2, to be used in the domain separation tag.
+pub const G2_SUITE: &[u8] = b"BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+/// Hash `msg` to G1 with the [`G1_SUITE`] hash to curve suite, using `dst` as
+/// domain separation tag.
+pub fn hash_to_g1<H: HostFunctions>(msg: &[u8], dst: &[u8]) -> Result<G1Affine<H>, HostCallError> {
+    let result = H::bls12_381_hash_to_g1(msg.to_vec(), dst.to_vec())
+        .map_err(|_| HostCallError::HostRejected)?;
+
+    let result = <ArkScale<G1Affine<H>> as Decode>::decode(&mut result.as_slice())
+        .map_err(|_| HostCallError::DecodeFailed)?;
+    Ok(result.0)
+}
+
+/// Hash `msg` to G2 with the [`G2_SUITE`] hash to curve suite, using `dst` as
+/// domain separation tag.
+pub fn hash_to_g2<H: HostFunctions>(msg: &[u8], dst: &[u8]) -> Result<G2Affine<H>, HostCallError> {
+    let result = H::bls12_381_hash_to_g2(msg.to_vec(), dst.to_vec())
+        .map_err(|_| HostCallError::HostRejected)?;
+
+    let result = <ArkScale<G2Affine<H>> as Decode>::decode(&mut result.as_slice())
+        .map_err(|_| HostCallError::DecodeFailed)?;
+    Ok(result.0)
+}
@@ -1,25 +1,37 @@
-use ark_std::{marker::PhantomData, vec::Vec};
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_serialize::{CanonicalDeserialize, SerializationError};
+use ark_std::{marker::PhantomData, vec::Vec, Zero};
 use codec::{Decode, Encode};
 use sp_ark_models::{
     bls12::{Bls12, Bls12Config, G1Prepared, G2Prepared, TwistType},
+    domain::FftHost,
     pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    software, torus, CurveGroup, Group, HostCallError, VariableBaseMSM,
 };
 
+mod cost;
+mod fallback;
 pub mod g1;
 pub mod g2;
+pub mod hashing;
+mod metered;
 pub(crate) mod util;
 
-use ark_bls12_381::{fq::Fq, fq12, fq2, fq6};
+use ark_bls12_381::{fq::Fq, fq12, fq2, fq6, Fr};
 
 const HOST_CALL: ark_scale::Usage = ark_scale::HOST_CALL;
 pub type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 
 pub use self::{
+    cost::HostCostModel,
+    fallback::Fallback,
     g1::{G1Affine, G1Projective},
     g2::{G2Affine, G2Projective},
+    hashing::{hash_to_g1, hash_to_g2},
+    metered::Metered,
 };
 
 pub struct Config<H: HostFunctions>(PhantomData<fn() -> H>);
@@ -31,6 +43,292 @@ pub trait HostFunctions: 'static {
     fn bls12_381_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()>;
     fn bls12_381_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()>;
     fn bls12_381_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()>;
+
+    /// Provided for hosts without a pairing check, which is then computed with a
+    /// Miller loop and a final exponentiation.
+    fn bls12_381_pairing_check(_a: Vec<u8>, _b: Vec<u8>) -> Result<bool, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without hashing to curve, which can be
+    /// wrapped in [`Fallback`] to hash in software.
+    fn bls12_381_hash_to_g1(_msg: Vec<u8>, _dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without hashing to curve, which can be
+    /// wrapped in [`Fallback`] to hash in software.
+    fn bls12_381_hash_to_g2(_msg: Vec<u8>, _dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without target group arithmetic, which can be
+    /// wrapped in [`Fallback`] to compute it in software.
+    fn bls12_381_mul_gt(_base: Vec<u8>, _scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without target group arithmetic, which can be
+    /// wrapped in [`Fallback`] to compute it in software.
+    fn bls12_381_msm_gt(_bases: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without a registry of bases, in which case
+    /// [`RegisteredBases`](crate::registry::RegisteredBases) computes plain MSMs.
+    fn bls12_381_register_bases_g1(_bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without a registry of bases, in which case
+    /// [`RegisteredBases`](crate::registry::RegisteredBases) computes plain MSMs.
+    fn bls12_381_msm_with_handle_g1(_handle: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without scalar field FFTs, which can be
+    /// wrapped in [`Fallback`] to compute them in software.
+    fn bls12_381_fft(_coeffs: Vec<u8>, _offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without scalar field FFTs, which can be
+    /// wrapped in [`Fallback`] to compute them in software.
+    fn bls12_381_ifft(_evals: Vec<u8>, _offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without scalar field batch inversion, which
+    /// can be wrapped in [`Fallback`] to compute it in software.
+    fn bls12_381_batch_inverse(_values: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without small scalar MSMs, in which case
+    /// [`SmallMsm`](sp_ark_models::small_msm::SmallMsm) computes plain MSMs.
+    fn bls12_381_msm_small_g1(_bases: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without small scalar MSMs, in which case
+    /// [`SmallMsm`](sp_ark_models::small_msm::SmallMsm) computes plain MSMs.
+    fn bls12_381_msm_small_g2(_bases: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batches, in which case
+    /// [`HostBatch`](crate::batch::HostBatch) makes one host call per operation.
+    fn bls12_381_batch(_calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched normalisation, in which
+    /// case the inputs of the Miller loop are normalised in WASM.
+    fn bls12_381_normalize_g1(_points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched normalisation, in which
+    /// case the inputs of the Miller loop are normalised in WASM.
+    fn bls12_381_normalize_g2(_points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched deserialization, in which
+    /// case the points are decompressed and checked in WASM.
+    fn bls12_381_deserialize_g1(_compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched deserialization, in which
+    /// case the points are decompressed and checked in WASM.
+    fn bls12_381_deserialize_g2(_compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+impl<H: HostFunctions> Config<H> {
+    /// Fallible version of [`Bls12Config::multi_miller_loop`], surfacing host call errors.
+    pub fn try_multi_miller_loop(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> Result<MillerLoopOutput<Bls12<Self>>, HostCallError> {
+        let a = a
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let b = b
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        if a.len() != b.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        Self::host_multi_miller_loop(&a, &b)
+    }
+
+    fn host_multi_miller_loop(
+        a: &[G1Prepared<Self>],
+        b: &[G2Prepared<Self>],
+    ) -> Result<MillerLoopOutput<Bls12<Self>>, HostCallError> {
+        let a: ArkScale<&[G1Prepared<Self>]> = a.into();
+        let b: ArkScale<&[G2Prepared<Self>]> = b.into();
+
+        let result = H::bls12_381_multi_miller_loop(a.encode(), b.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        decode_miller_loop_output(result)
+    }
+
+    /// Software multi Miller loop, for when the host fails to compute it.
+    fn software_multi_miller_loop(
+        a: &[G1Prepared<Self>],
+        b: &[G2Prepared<Self>],
+    ) -> MillerLoopOutput<Bls12<Self>> {
+        let a: ArkScale<&[G1Prepared<Self>]> = a.into();
+        let b: ArkScale<&[G2Prepared<Self>]> = b.into();
+
+        software::multi_miller_loop::<ark_bls12_381::Bls12_381>(&a.encode(), &b.encode())
+            .map_err(|_| HostCallError::DecodeFailed)
+            .and_then(decode_miller_loop_output)
+            .expect("prepared points encode as affine points")
+    }
+
+    /// Fallible version of [`Bls12Config::final_exponentiation`], surfacing host call errors.
+    pub fn try_final_exponentiation(
+        f: MillerLoopOutput<Bls12<Self>>,
+    ) -> Result<PairingOutput<Bls12<Self>>, HostCallError> {
+        let target: ArkScale<<Bls12<Self> as Pairing>::TargetField> = f.0.into();
+
+        let result = H::bls12_381_final_exponentiation(target.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        torus::decode_host_output(&result)
+    }
+
+    /// Fallible version of [`Bls12Config::multi_pairing_is_one`], surfacing host call errors.
+    pub fn try_multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> Result<bool, HostCallError> {
+        let a = a
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let b = b
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        if a.len() != b.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        Self::host_pairing_check(&a, &b)
+    }
+
+    fn host_pairing_check(
+        a: &[G1Prepared<Self>],
+        b: &[G2Prepared<Self>],
+    ) -> Result<bool, HostCallError> {
+        let a: ArkScale<&[G1Prepared<Self>]> = a.into();
+        let b: ArkScale<&[G2Prepared<Self>]> = b.into();
+
+        H::bls12_381_pairing_check(a.encode(), b.encode()).map_err(|_| HostCallError::HostRejected)
+    }
+
+    /// Fallible version of [`Bls12Config::gt_mul`], surfacing host call errors.
+    pub fn try_gt_mul(
+        base: &PairingOutput<Bls12<Self>>,
+        scalar: &[u64],
+    ) -> Result<PairingOutput<Bls12<Self>>, HostCallError> {
+        let base: ArkScale<PairingOutput<Bls12<Self>>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result = H::bls12_381_mul_gt(base.encode(), scalar.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        torus::decode_host_output(&result)
+    }
+
+    /// Fallible version of [`Bls12Config::gt_msm`], surfacing host call errors.
+    pub fn try_gt_msm(
+        bases: &[PairingOutput<Bls12<Self>>],
+        scalars: &[<Bls12<Self> as Pairing>::ScalarField],
+    ) -> Result<PairingOutput<Bls12<Self>>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[PairingOutput<Bls12<Self>>]> = bases.into();
+        let scalars: ArkScale<&[<Bls12<Self> as Pairing>::ScalarField]> = scalars.into();
+
+        let result = H::bls12_381_msm_gt(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        torus::decode_host_output(&result)
+    }
+
+    /// Fallible version of [`Bls12Config::normalize_g1`], surfacing host call errors.
+    pub fn try_normalize_g1(points: &[G1Projective<H>]) -> Result<Vec<G1Affine<H>>, HostCallError> {
+        let mut encoded = (points.len() as u64).encode();
+        for p in points {
+            ArkScaleProjective::from(p).encode_to(&mut encoded);
+        }
+
+        let result = H::bls12_381_normalize_g1(encoded).map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G1Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        if result.0.len() != points.len() {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`Bls12Config::normalize_g2`], surfacing host call errors.
+    pub fn try_normalize_g2(points: &[G2Projective<H>]) -> Result<Vec<G2Affine<H>>, HostCallError> {
+        let mut encoded = (points.len() as u64).encode();
+        for p in points {
+            ArkScaleProjective::from(p).encode_to(&mut encoded);
+        }
+
+        let result = H::bls12_381_normalize_g2(encoded).map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G2Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        if result.0.len() != points.len() {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`Bls12Config::deserialize_g1_batch`], surfacing host call
+    /// errors.
+    pub fn try_deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<H>>, HostCallError> {
+        let result = H::bls12_381_deserialize_g1(compressed.to_vec())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G1Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        // The compressed encoding starts with the number of points.
+        if u64::decode(&mut &compressed[..]).ok() != Some(result.0.len() as u64) {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`Bls12Config::deserialize_g2_batch`], surfacing host call
+    /// errors.
+    pub fn try_deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<H>>, HostCallError> {
+        let result = H::bls12_381_deserialize_g2(compressed.to_vec())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G2Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        // The compressed encoding starts with the number of points.
+        if u64::decode(&mut &compressed[..]).ok() != Some(result.0.len() as u64) {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
 }
 
 impl<H: HostFunctions> Bls12Config for Config<H> {
@@ -48,43 +346,131 @@ impl<H: HostFunctions> Bls12Config for Config<H> {
         a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
         b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
     ) -> MillerLoopOutput<Bls12<Self>> {
-        let a: ArkScale<Vec<<Bls12<Self> as Pairing>::G1Prepared>> = a
+        let mut a = a
             .into_iter()
-            .map(|el| {
-                let el: <Bls12<Self> as Pairing>::G1Prepared = el.into();
-                el
-            })
-            .collect::<Vec<_>>()
-            .into();
-        let b: ArkScale<Vec<<Bls12<Self> as Pairing>::G2Prepared>> = b
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let mut b = b
             .into_iter()
-            .map(|el| {
-                let el: <Bls12<Self> as Pairing>::G2Prepared = el.into();
-                el
-            })
-            .collect::<Vec<_>>()
-            .into();
-
-        let result = H::bls12_381_multi_miller_loop(a.encode(), b.encode()).unwrap();
-
-        let result = <ArkScale<<Bls12<Self> as Pairing>::TargetField> as Decode>::decode(
-            &mut result.as_slice(),
-        );
-        MillerLoopOutput(result.unwrap().0)
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        // Like arkworks, pair the inputs up to the shorter of the two.
+        let len = a.len().min(b.len());
+        a.truncate(len);
+        b.truncate(len);
+        Self::host_multi_miller_loop(&a, &b)
+            .unwrap_or_else(|_| Self::software_multi_miller_loop(&a, &b))
     }
 
     fn final_exponentiation(
         f: MillerLoopOutput<Bls12<Self>>,
     ) -> Option<PairingOutput<Bls12<Self>>> {
-        let target: ArkScale<<Bls12<Self> as Pairing>::TargetField> = f.0.into();
+        Self::try_final_exponentiation(f).ok()
+    }
+
+    fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> bool {
+        let a = a
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let b = b
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        if a.len() != b.len() {
+            return false;
+        }
+        // Hosts without a pairing check compute it with their Miller loop and final
+        // exponentiation.
+        Self::host_pairing_check(&a, &b).unwrap_or_else(|_| {
+            let f = Self::host_multi_miller_loop(&a, &b)
+                .unwrap_or_else(|_| Self::software_multi_miller_loop(&a, &b));
+            Self::try_final_exponentiation(f).is_ok_and(|f| f.is_zero())
+        })
+    }
+
+    fn gt_mul(base: &PairingOutput<Bls12<Self>>, scalar: &[u64]) -> PairingOutput<Bls12<Self>> {
+        Self::try_gt_mul(base, scalar).unwrap_or_else(|_| base.mul_bigint(scalar))
+    }
+
+    fn gt_msm(
+        bases: &[PairingOutput<Bls12<Self>>],
+        scalars: &[<Bls12<Self> as Pairing>::ScalarField],
+    ) -> Result<PairingOutput<Bls12<Self>>, usize> {
+        match Self::try_gt_msm(bases, scalars) {
+            Ok(result) => Ok(result),
+            Err(_) => VariableBaseMSM::msm(bases, scalars),
+        }
+    }
+
+    fn normalize_g1(points: &[G1Projective<H>]) -> Vec<G1Affine<H>> {
+        Self::try_normalize_g1(points)
+            .unwrap_or_else(|_| G1Projective::<H>::normalize_batch(points))
+    }
+
+    fn normalize_g2(points: &[G2Projective<H>]) -> Vec<G2Affine<H>> {
+        Self::try_normalize_g2(points)
+            .unwrap_or_else(|_| G2Projective::<H>::normalize_batch(points))
+    }
+
+    fn deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<H>>, SerializationError> {
+        Self::try_deserialize_g1_batch(compressed)
+            .or_else(|_| Vec::deserialize_compressed(compressed))
+    }
 
-        let result = H::bls12_381_final_exponentiation(target.encode()).unwrap();
+    fn deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<H>>, SerializationError> {
+        Self::try_deserialize_g2_batch(compressed)
+            .or_else(|_| Vec::deserialize_compressed(compressed))
+    }
+}
+
+impl<H: HostFunctions> FftHost<Fr> for Config<H> {
+    fn fft(coeffs: &[Fr], offset: Fr) -> Result<Vec<Fr>, HostCallError> {
+        let coeffs: ArkScale<&[Fr]> = coeffs.into();
+        let offset: ArkScale<Fr> = offset.into();
+
+        let result = H::bls12_381_fft(coeffs.encode(), offset.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        decode_scalars(result)
+    }
+
+    fn ifft(evals: &[Fr], offset: Fr) -> Result<Vec<Fr>, HostCallError> {
+        let evals: ArkScale<&[Fr]> = evals.into();
+        let offset: ArkScale<Fr> = offset.into();
+
+        let result = H::bls12_381_ifft(evals.encode(), offset.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        decode_scalars(result)
+    }
+
+    fn batch_inverse(values: &[Fr]) -> Result<Vec<Fr>, HostCallError> {
+        let values: ArkScale<&[Fr]> = values.into();
 
         let result =
-            <ArkScale<PairingOutput<Bls12<Self>>> as Decode>::decode(&mut result.as_slice());
+            H::bls12_381_batch_inverse(values.encode()).map_err(|_| HostCallError::HostRejected)?;
 
-        result.ok().map(|res| res.0)
+        decode_scalars(result)
     }
 }
 
+fn decode_miller_loop_output<P: Bls12Config>(
+    result: Vec<u8>,
+) -> Result<MillerLoopOutput<Bls12<P>>, HostCallError> {
+    let result =
+        <ArkScale<<Bls12<P> as Pairing>::TargetField> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+    Ok(MillerLoopOutput(result.0))
+}
+
+fn decode_scalars(result: Vec<u8>) -> Result<Vec<Fr>, HostCallError> {
+    let result = <ArkScale<Vec<Fr>> as Decode>::decode(&mut result.as_slice())
+        .map_err(|_| HostCallError::DecodeFailed)?;
+    Ok(result.0)
+}
+
 pub type Bls12_381<H> = Bls12<Config<H>>;
@@ -6,13 +6,13 @@ use ark_std::{rand::Rng, test_rng, vec, UniformRand};
 use sp_ark_models::{pairing::PairingOutput, AffineRepr, CurveGroup, Group};
 
 use crate::{
-    fq::Fq, fq2::Fq2, fr::Fr, Bls12_381 as Bls12_381Host, G1Affine as G1AffineHost,
+    fq::Fq, fq2::Fq2, fr::Fr, Bls12_381 as Bls12_381Host, Fallback, G1Affine as G1AffineHost,
     G1Projective as G1ProjectiveHost, G2Affine as G2AffineHost, G2Projective as G2ProjectiveHost,
     HostFunctions,
 };
 
 #[derive(PartialEq, Eq)]
-struct Host;
+pub(crate) struct Host;
 
 impl HostFunctions for Host {
     fn bls12_381_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
@@ -46,6 +46,86 @@ test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; PairingOutput<Bls12_381>; msm);
 test_pairing!(ark_pairing; super::Bls12_381);
 
+struct Unavailable;
+
+impl HostFunctions for Unavailable {
+    fn bls12_381_multi_miller_loop(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_final_exponentiation(_: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_msm_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_msm_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_mul_projective_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_mul_projective_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+type Bls12_381Fallback = Bls12_381Host<Fallback<Unavailable>>;
+type G1ProjectiveFallback = G1ProjectiveHost<Fallback<Unavailable>>;
+type G2ProjectiveFallback = G2ProjectiveHost<Fallback<Unavailable>>;
+
+test_group!(g1_fallback; G1ProjectiveFallback; sw);
+test_group!(g2_fallback; G2ProjectiveFallback; sw);
+test_pairing!(pairing_fallback; super::Bls12_381Fallback);
+
+#[test]
+fn test_multi_pairing_is_one() {
+    let mut rng = test_rng();
+    let a = Fr::rand(&mut rng);
+    let b = Fr::rand(&mut rng);
+
+    let p = [
+        G1Projective::generator() * a,
+        -G1Projective::generator() * (a * b),
+    ];
+    let q = [G2Projective::generator() * b, G2Projective::generator()];
+    assert!(Bls12_381::multi_pairing_is_one(p, q));
+
+    let p = [
+        G1Projective::generator() * a,
+        G1Projective::generator() * (a * b),
+    ];
+    assert!(!Bls12_381::multi_pairing_is_one(p, q));
+
+    let p = [
+        G1ProjectiveFallback::generator() * a,
+        -G1ProjectiveFallback::generator() * (a * b),
+    ];
+    let q = [
+        G2ProjectiveFallback::generator() * b,
+        G2ProjectiveFallback::generator(),
+    ];
+    assert!(Bls12_381Fallback::multi_pairing_is_one(p, q));
+}
+
+#[test]
+fn test_fallback_matches_host() {
+    use sp_ark_models::pairing::Pairing;
+
+    let mut rng = test_rng();
+    let s = <Bls12_381 as Pairing>::ScalarField::rand(&mut rng);
+
+    let p = (G1Projective::generator() * s).into_affine();
+    let q = (G2Projective::generator() * s).into_affine();
+    let p_fallback = (G1ProjectiveFallback::generator() * s).into_affine();
+    let q_fallback = (G2ProjectiveFallback::generator() * s).into_affine();
+    assert_eq!(p.xy(), p_fallback.xy());
+    assert_eq!(q.xy(), q_fallback.xy());
+
+    let expected = Bls12_381::pairing(p, q);
+    let result = Bls12_381Fallback::pairing(p_fallback, q_fallback);
+    assert_eq!(expected.0, result.0);
+}
+
 #[test]
 fn test_g1_endomorphism_beta() {
     assert!(crate::g1::BETA.pow([3u64]).is_one());
@@ -152,3 +232,90 @@ fn g2_uncompressed_valid_test_vectors() {
     let bytes: &'static [u8] = include_bytes!("g2_uncompressed_valid_test_vectors.dat");
     test_vectors!(G2Projective, G2Affine, Compress::No, bytes);
 }
+
+#[test]
+fn test_host_cost_model_matches_benchmarks() {
+    use crate::HostCostModel;
+    use sp_ark_models::{cost::CostModel, metering::Operation};
+
+    // Benchmark figures in µs, matched within 0.1%.
+    let assert_close = |cost: u64, expected: f64| {
+        assert!((cost as f64 / 1000.0 - expected).abs() < expected / 1000.0);
+    };
+    let cost = |op, args| HostCostModel::cost(op, args).unwrap();
+    assert_close(cost(Operation::MsmG1, 10), 949.58);
+    assert_close(cost(Operation::MsmG1, 1000), 30158.23);
+    assert_close(cost(Operation::MsmG2, 10), 2870.33);
+    assert_close(cost(Operation::MsmG2, 1000), 100801.74);
+    assert_close(
+        cost(Operation::MultiMillerLoop, 1) + cost(Operation::FinalExponentiation, 1),
+        1390.80,
+    );
+    assert_eq!(HostCostModel::cost(Operation::TeMsm, 1), None);
+}
+
+// RFC 9380, appendices J.9.1 and J.10.1.
+const HASH_TO_CURVE_MSGS: [&str; 3] = ["", "abc", "abcdef0123456789"];
+
+#[test]
+fn test_hash_to_g1_rfc9380_vectors() {
+    let dst = [b"QUUX-V01-CS02-with-".as_slice(), crate::hashing::G1_SUITE].concat();
+    let expected = [
+        (
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ),
+        (
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ),
+        (
+            "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+            "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+        ),
+    ];
+    assert_eq!(expected.len(), HASH_TO_CURVE_MSGS.len());
+    for (msg, (x, y)) in HASH_TO_CURVE_MSGS.iter().zip(expected) {
+        let p = crate::hash_to_g1::<Fallback<Host>>(msg.as_bytes(), &dst).unwrap();
+        assert_eq!(p.x, fq_from_hex(x));
+        assert_eq!(p.y, fq_from_hex(y));
+    }
+}
+
+#[test]
+fn test_hash_to_g2_rfc9380_vectors() {
+    let dst = [b"QUUX-V01-CS02-with-".as_slice(), crate::hashing::G2_SUITE].concat();
+    let expected = [
+        (
+            ("0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+            "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d"),
+            ("0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+            "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6"),
+        ),
+        (
+            ("02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+            "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8"),
+            ("1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+            "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16"),
+        ),
+        (
+            ("121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0",
+            "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c"),
+            ("05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+            "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be"),
+        ),
+    ];
+    assert_eq!(expected.len(), HASH_TO_CURVE_MSGS.len());
+    for (msg, ((x0, x1), (y0, y1))) in HASH_TO_CURVE_MSGS.iter().zip(expected) {
+        let p = crate::hash_to_g2::<Fallback<Host>>(msg.as_bytes(), &dst).unwrap();
+        assert_eq!(p.x, Fq2::new(fq_from_hex(x0), fq_from_hex(x1)));
+        assert_eq!(p.y, Fq2::new(fq_from_hex(y0), fq_from_hex(y1)));
+    }
+}
+
+fn fq_from_hex(hex: &str) -> Fq {
+    use ark_ff::PrimeField;
+    let mut bytes = [0u8; 48];
+    hex::decode_to_slice(hex, &mut bytes).unwrap();
+    Fq::from_be_bytes_mod_order(&bytes)
+}
@@ -0,0 +1,299 @@
+//! Blob KZG proof verification of [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844),
+//! following the polynomial commitments of the Ethereum consensus specification.
+//!
+//! Commitments and proofs use the zcash compressed point encoding. Combining the
+//! pairing inputs and the pairing check go through the host functions.
+
+use ark_ff::{batch_inversion, BigInteger, Field, One, PrimeField, Zero};
+use ark_serialize::CanonicalDeserialize;
+use ark_std::{fmt, vec::Vec};
+use sha2::{Digest, Sha256};
+use sp_ark_models::{
+    kzg::{self, Opening, VerifierKey},
+    AffineRepr, HostCallError,
+};
+
+use crate::{Bls12_381, Fr, G1Affine, G2Affine, HostFunctions};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of field elements of a blob.
+pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
+/// Size of an encoded field element.
+pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
+/// Size of a blob.
+pub const BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+/// Size of an encoded commitment.
+pub const BYTES_PER_COMMITMENT: usize = 48;
+/// Size of an encoded proof.
+pub const BYTES_PER_PROOF: usize = 48;
+
+const FIAT_SHAMIR_PROTOCOL_DOMAIN: &[u8] = b"FSBLOBVERIFY_V1_";
+const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: &[u8] = b"RCKZGBATCH___V1_";
+const PRIMITIVE_ROOT_OF_UNITY: u64 = 7;
+const G1_POINT_AT_INFINITY: [u8; 48] = {
+    let mut bytes = [0; 48];
+    bytes[0] = 0xc0;
+    bytes
+};
+
+/// Blob of field elements, each encoded big endian.
+pub type Blob = [u8; BYTES_PER_BLOB];
+/// Encoded field element.
+pub type Bytes32 = [u8; 32];
+/// Encoded commitment or proof.
+pub type Bytes48 = [u8; 48];
+
+/// Error of the blob KZG proof verification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A field element is not smaller than the modulus.
+    InvalidFieldElement,
+    /// A commitment or proof is not a valid point of the prime order subgroup.
+    InvalidPoint,
+    /// The batch inputs have different lengths.
+    LengthMismatch,
+    /// A host call failed.
+    HostCall(HostCallError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidFieldElement => write!(f, "invalid field element"),
+            Error::InvalidPoint => write!(f, "invalid commitment or proof"),
+            Error::LengthMismatch => write!(f, "input lengths do not match"),
+            Error::HostCall(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<HostCallError> for Error {
+    fn from(e: HostCallError) -> Self {
+        Error::HostCall(e)
+    }
+}
+
+/// The part of the KZG trusted setup used for verification, i.e. `KZG_SETUP_G2_MONOMIAL[1]`.
+pub struct Settings<H: HostFunctions> {
+    vk: VerifierKey<Bls12_381<H>>,
+}
+
+impl<H: HostFunctions> Settings<H> {
+    /// Settings of the trusted setup with `[τ]₂` being `tau_g2`.
+    pub fn new(tau_g2: G2Affine<H>) -> Self {
+        Settings {
+            vk: VerifierKey::new(
+                G1Affine::<H>::generator(),
+                G2Affine::<H>::generator(),
+                tau_g2,
+            ),
+        }
+    }
+
+    /// Settings of the trusted setup with `[τ]₂` encoded as `tau_g2`.
+    pub fn from_bytes(tau_g2: &[u8; 96]) -> Result<Self, Error> {
+        G2Affine::<H>::deserialize_compressed(&tau_g2[..])
+            .map(Self::new)
+            .map_err(|_| Error::InvalidPoint)
+    }
+}
+
+/// Verify the proof `proof` that the polynomial committed to by `commitment`
+/// evaluates to `y` at `z`.
+pub fn verify_kzg_proof<H: HostFunctions>(
+    settings: &Settings<H>,
+    commitment: &Bytes48,
+    z: &Bytes32,
+    y: &Bytes32,
+    proof: &Bytes48,
+) -> Result<bool, Error> {
+    verify_kzg_proof_impl(
+        settings,
+        bytes_to_kzg_point(commitment)?,
+        bytes_to_bls_field(z)?,
+        bytes_to_bls_field(y)?,
+        bytes_to_kzg_point(proof)?,
+    )
+}
+
+/// Verify the proof `proof` that `commitment` commits to `blob`.
+pub fn verify_blob_kzg_proof<H: HostFunctions>(
+    settings: &Settings<H>,
+    blob: &Blob,
+    commitment: &Bytes48,
+    proof: &Bytes48,
+) -> Result<bool, Error> {
+    let roots_of_unity = roots_of_unity_brp();
+    let opening = blob_opening::<H>(blob, commitment, proof, &roots_of_unity)?;
+    verify_kzg_proof_impl(
+        settings,
+        opening.commitment,
+        opening.point,
+        opening.value,
+        opening.proof,
+    )
+}
+
+/// Verify the proofs `proofs` that `commitments` commit to `blobs`, all at once.
+pub fn verify_blob_kzg_proof_batch<H: HostFunctions>(
+    settings: &Settings<H>,
+    blobs: &[Blob],
+    commitments: &[Bytes48],
+    proofs: &[Bytes48],
+) -> Result<bool, Error> {
+    if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+        return Err(Error::LengthMismatch);
+    }
+    if blobs.is_empty() {
+        return Ok(true);
+    }
+    let roots_of_unity = roots_of_unity_brp();
+    let openings = blobs
+        .iter()
+        .zip(commitments)
+        .zip(proofs)
+        .map(|((blob, commitment), proof)| {
+            blob_opening::<H>(blob, commitment, proof, &roots_of_unity)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut data = Vec::with_capacity(32 + openings.len() * 160);
+    data.extend_from_slice(RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
+    data.extend_from_slice(&(FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
+    data.extend_from_slice(&(openings.len() as u64).to_be_bytes());
+    for ((opening, commitment), proof) in openings.iter().zip(commitments).zip(proofs) {
+        data.extend_from_slice(commitment);
+        data.extend_from_slice(&opening.point.into_bigint().to_bytes_be());
+        data.extend_from_slice(&opening.value.into_bigint().to_bytes_be());
+        data.extend_from_slice(proof);
+    }
+    let r = hash_to_bls_field(&data);
+
+    Ok(kzg::verify_batch_with_challenge(
+        &settings.vk,
+        &openings,
+        r,
+    )?)
+}
+
+fn verify_kzg_proof_impl<H: HostFunctions>(
+    settings: &Settings<H>,
+    commitment: G1Affine<H>,
+    z: Fr,
+    y: Fr,
+    proof: G1Affine<H>,
+) -> Result<bool, Error> {
+    Ok(kzg::verify(&settings.vk, &commitment, z, y, &proof)?)
+}
+
+/// Opening of the polynomial of `blob` at its Fiat-Shamir challenge.
+fn blob_opening<H: HostFunctions>(
+    blob: &Blob,
+    commitment: &Bytes48,
+    proof: &Bytes48,
+    roots_of_unity: &[Fr],
+) -> Result<Opening<Bls12_381<H>>, Error> {
+    let polynomial = blob_to_polynomial(blob)?;
+    let point = compute_challenge(blob, commitment);
+    Ok(Opening {
+        commitment: bytes_to_kzg_point(commitment)?,
+        point,
+        value: evaluate_polynomial_in_evaluation_form(&polynomial, point, roots_of_unity),
+        proof: bytes_to_kzg_point(proof)?,
+    })
+}
+
+fn bytes_to_bls_field(bytes: &Bytes32) -> Result<Fr, Error> {
+    let field = Fr::from_be_bytes_mod_order(bytes);
+    if field.into_bigint().to_bytes_be() == bytes {
+        Ok(field)
+    } else {
+        Err(Error::InvalidFieldElement)
+    }
+}
+
+fn hash_to_bls_field(data: &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(&Sha256::digest(data))
+}
+
+/// Decode a commitment or proof, the identity only being accepted in its canonical
+/// encoding.
+fn bytes_to_kzg_point<H: HostFunctions>(bytes: &Bytes48) -> Result<G1Affine<H>, Error> {
+    if *bytes == G1_POINT_AT_INFINITY {
+        return Ok(G1Affine::<H>::zero());
+    }
+    let point =
+        G1Affine::<H>::deserialize_compressed(&bytes[..]).map_err(|_| Error::InvalidPoint)?;
+    if point.is_zero() {
+        return Err(Error::InvalidPoint);
+    }
+    Ok(point)
+}
+
+fn blob_to_polynomial(blob: &Blob) -> Result<Vec<Fr>, Error> {
+    blob.chunks_exact(BYTES_PER_FIELD_ELEMENT)
+        .map(|chunk| {
+            let mut bytes = [0; BYTES_PER_FIELD_ELEMENT];
+            bytes.copy_from_slice(chunk);
+            bytes_to_bls_field(&bytes)
+        })
+        .collect()
+}
+
+fn compute_challenge(blob: &Blob, commitment: &Bytes48) -> Fr {
+    let mut hasher = Sha256::new();
+    hasher.update(FIAT_SHAMIR_PROTOCOL_DOMAIN);
+    hasher.update((FIELD_ELEMENTS_PER_BLOB as u128).to_be_bytes());
+    hasher.update(blob);
+    hasher.update(commitment);
+    Fr::from_be_bytes_mod_order(&hasher.finalize())
+}
+
+/// The `FIELD_ELEMENTS_PER_BLOB`-th roots of unity in bit reversal order.
+pub(crate) fn roots_of_unity_brp() -> Vec<Fr> {
+    let mut exponent = Fr::MODULUS;
+    exponent.sub_with_borrow(&1u64.into());
+    exponent.divn(FIELD_ELEMENTS_PER_BLOB.trailing_zeros());
+    let root = Fr::from(PRIMITIVE_ROOT_OF_UNITY).pow(exponent);
+
+    let mut roots = Vec::with_capacity(FIELD_ELEMENTS_PER_BLOB);
+    let mut current = Fr::one();
+    for _ in 0..FIELD_ELEMENTS_PER_BLOB {
+        roots.push(current);
+        current *= root;
+    }
+    let shift = usize::BITS - FIELD_ELEMENTS_PER_BLOB.trailing_zeros();
+    (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|i| roots[i.reverse_bits() >> shift])
+        .collect()
+}
+
+/// Evaluate at `z` the polynomial taking the values `polynomial` at `roots_of_unity`.
+pub(crate) fn evaluate_polynomial_in_evaluation_form(
+    polynomial: &[Fr],
+    z: Fr,
+    roots_of_unity: &[Fr],
+) -> Fr {
+    if let Some(i) = roots_of_unity.iter().position(|root| *root == z) {
+        return polynomial[i];
+    }
+    let mut denominators = roots_of_unity
+        .iter()
+        .map(|root| z - root)
+        .collect::<Vec<_>>();
+    batch_inversion(&mut denominators);
+    let result = polynomial
+        .iter()
+        .zip(roots_of_unity)
+        .zip(denominators)
+        .fold(Fr::zero(), |acc, ((value, root), inverse)| {
+            acc + *value * root * inverse
+        });
+    let width = Fr::from(polynomial.len() as u64);
+    result * (z.pow([polynomial.len() as u64]) - Fr::one()) * width.inverse().unwrap_or_default()
+}
@@ -0,0 +1,267 @@
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{rand::RngCore, test_rng, vec, vec::Vec, UniformRand};
+use sp_ark_models::Group;
+
+use super::*;
+use crate::{curves::tests::Host, G1Projective, G2Projective};
+
+/// Trusted setup with a known trapdoor, committing and proving in the clear.
+struct Setup {
+    tau: Fr,
+    settings: Settings<Host>,
+    roots_of_unity: Vec<Fr>,
+}
+
+impl Setup {
+    fn new() -> Self {
+        let tau = Fr::rand(&mut test_rng());
+        Setup {
+            tau,
+            settings: Settings::new((G2Projective::<Host>::generator() * tau).into()),
+            roots_of_unity: roots_of_unity_brp(),
+        }
+    }
+
+    fn commit(&self, blob: &Blob) -> Bytes48 {
+        let polynomial = blob_to_polynomial(blob).unwrap();
+        let value =
+            evaluate_polynomial_in_evaluation_form(&polynomial, self.tau, &self.roots_of_unity);
+        g1_bytes(value)
+    }
+
+    fn prove(&self, blob: &Blob, z: Fr) -> (Fr, Bytes48) {
+        let polynomial = blob_to_polynomial(blob).unwrap();
+        let at_tau =
+            evaluate_polynomial_in_evaluation_form(&polynomial, self.tau, &self.roots_of_unity);
+        let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, &self.roots_of_unity);
+        let quotient = (at_tau - y) * (self.tau - z).inverse().unwrap();
+        (y, g1_bytes(quotient))
+    }
+
+    fn prove_blob(&self, blob: &Blob, commitment: &Bytes48) -> Bytes48 {
+        self.prove(blob, compute_challenge(blob, commitment)).1
+    }
+}
+
+fn g1_bytes(scalar: Fr) -> Bytes48 {
+    let mut bytes = [0; 48];
+    G1Affine::<Host>::from(G1Projective::<Host>::generator() * scalar)
+        .serialize_compressed(&mut bytes[..])
+        .unwrap();
+    bytes
+}
+
+fn field_bytes(field: Fr) -> Bytes32 {
+    let mut bytes = [0; 32];
+    bytes.copy_from_slice(&field.into_bigint().to_bytes_be());
+    bytes
+}
+
+fn random_blob<R: RngCore>(rng: &mut R) -> Blob {
+    let mut blob = [0; BYTES_PER_BLOB];
+    for chunk in blob.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT) {
+        chunk.copy_from_slice(&field_bytes(Fr::rand(rng)));
+    }
+    blob
+}
+
+#[test]
+fn roots_of_unity() {
+    let roots = roots_of_unity_brp();
+    assert_eq!(roots.len(), FIELD_ELEMENTS_PER_BLOB);
+    assert_eq!(roots[0], Fr::one());
+    assert_eq!(roots[1], -Fr::one());
+    assert!(roots
+        .iter()
+        .all(|root| root.pow([FIELD_ELEMENTS_PER_BLOB as u64]) == Fr::one()));
+    assert_ne!(
+        roots[FIELD_ELEMENTS_PER_BLOB / 2].pow([FIELD_ELEMENTS_PER_BLOB as u64 / 2]),
+        Fr::one()
+    );
+}
+
+#[test]
+fn zero_blob() {
+    // Holds for any trusted setup.
+    let setup = Setup::new();
+    let blob = [0; BYTES_PER_BLOB];
+    assert_eq!(
+        verify_blob_kzg_proof(
+            &setup.settings,
+            &blob,
+            &G1_POINT_AT_INFINITY,
+            &G1_POINT_AT_INFINITY
+        ),
+        Ok(true)
+    );
+    assert_eq!(
+        verify_kzg_proof(
+            &setup.settings,
+            &G1_POINT_AT_INFINITY,
+            &[1; 32],
+            &[0; 32],
+            &G1_POINT_AT_INFINITY
+        ),
+        Ok(true)
+    );
+}
+
+#[test]
+fn kzg_proof() {
+    let setup = Setup::new();
+    let blob = random_blob(&mut test_rng());
+    let commitment = setup.commit(&blob);
+    let z = Fr::from(42u8);
+    let (y, proof) = setup.prove(&blob, z);
+
+    assert_eq!(
+        verify_kzg_proof(
+            &setup.settings,
+            &commitment,
+            &field_bytes(z),
+            &field_bytes(y),
+            &proof
+        ),
+        Ok(true)
+    );
+    let wrong_y = field_bytes(y + Fr::one());
+    assert_eq!(
+        verify_kzg_proof(
+            &setup.settings,
+            &commitment,
+            &field_bytes(z),
+            &wrong_y,
+            &proof
+        ),
+        Ok(false)
+    );
+
+    // At a root of unity the value is read off the blob.
+    let z = setup.roots_of_unity[3];
+    let (y, proof) = setup.prove(&blob, z);
+    assert_eq!(field_bytes(y)[..], blob[3 * 32..4 * 32]);
+    assert_eq!(
+        verify_kzg_proof(
+            &setup.settings,
+            &commitment,
+            &field_bytes(z),
+            &field_bytes(y),
+            &proof
+        ),
+        Ok(true)
+    );
+}
+
+#[test]
+fn blob_kzg_proof() {
+    let setup = Setup::new();
+    let blob = random_blob(&mut test_rng());
+    let commitment = setup.commit(&blob);
+    let proof = setup.prove_blob(&blob, &commitment);
+    assert_eq!(
+        verify_blob_kzg_proof(&setup.settings, &blob, &commitment, &proof),
+        Ok(true)
+    );
+
+    let mut wrong_blob = blob;
+    wrong_blob[31] ^= 1;
+    assert_eq!(
+        verify_blob_kzg_proof(&setup.settings, &wrong_blob, &commitment, &proof),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof(&setup.settings, &blob, &commitment, &commitment),
+        Ok(false)
+    );
+}
+
+#[test]
+fn blob_kzg_proof_batch() {
+    let setup = Setup::new();
+    let mut rng = test_rng();
+    let blobs = vec![
+        random_blob(&mut rng),
+        [0; BYTES_PER_BLOB],
+        random_blob(&mut rng),
+    ];
+    let commitments = blobs
+        .iter()
+        .map(|blob| setup.commit(blob))
+        .collect::<Vec<_>>();
+    let proofs = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| setup.prove_blob(blob, commitment))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&setup.settings, &blobs, &commitments, &proofs),
+        Ok(true)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&setup.settings, &[], &[], &[]),
+        Ok(true)
+    );
+
+    let mut wrong_proofs = proofs.clone();
+    wrong_proofs.swap(0, 2);
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&setup.settings, &blobs, &commitments, &wrong_proofs),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&setup.settings, &blobs, &commitments[1..], &proofs),
+        Err(Error::LengthMismatch)
+    );
+}
+
+#[test]
+fn invalid_inputs() {
+    let setup = Setup::new();
+    let blob = random_blob(&mut test_rng());
+    let commitment = setup.commit(&blob);
+    let proof = setup.prove_blob(&blob, &commitment);
+
+    // Field element equal to the modulus.
+    let mut invalid_blob = blob;
+    invalid_blob[..32].copy_from_slice(&Fr::MODULUS.to_bytes_be());
+    assert_eq!(
+        verify_blob_kzg_proof(&setup.settings, &invalid_blob, &commitment, &proof),
+        Err(Error::InvalidFieldElement)
+    );
+
+    // Non canonical identity.
+    let mut invalid_point = G1_POINT_AT_INFINITY;
+    invalid_point[47] = 1;
+    assert_eq!(
+        verify_blob_kzg_proof(&setup.settings, &blob, &invalid_point, &proof),
+        Err(Error::InvalidPoint)
+    );
+
+    // Missing compression flag.
+    let mut invalid_point = commitment;
+    invalid_point[0] &= 0x7f;
+    assert_eq!(
+        verify_blob_kzg_proof(&setup.settings, &blob, &commitment, &invalid_point),
+        Err(Error::InvalidPoint)
+    );
+
+    // Point outside of the prime order subgroup.
+    let mut x = 0u8;
+    let invalid_point = loop {
+        x += 1;
+        let mut bytes = [0; 48];
+        bytes[0] = 0x80;
+        bytes[47] = x;
+        if let Ok(point) = crate::curves::util::read_g1_compressed::<_, Host>(&bytes[..]) {
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                break bytes;
+            }
+        }
+    };
+    assert_eq!(
+        verify_blob_kzg_proof(&setup.settings, &blob, &invalid_point, &proof),
+        Err(Error::InvalidPoint)
+    );
+}
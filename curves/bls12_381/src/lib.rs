@@ -25,7 +25,13 @@
 //! * G1 curve equation: y^2 = x^3 + 4
 //! * G2 curve equation: y^2 = x^3 + Fq2(4, 4)
 
+pub mod batch;
+pub mod bls;
 pub mod curves;
+pub mod domain;
+pub mod eip4844;
+pub mod pedersen;
+pub mod registry;
 
 pub use ark_bls12_381::{fq, fq::*, fq12, fq12::*, fq2, fq2::*, fq6, fq6::*, fr, fr::*};
 pub use curves::*;
@@ -0,0 +1,12 @@
+//! Pedersen vector commitments over G1, see [`sp_ark_models::pedersen`].
+
+use sp_ark_models::pedersen;
+
+use crate::G1Projective;
+
+pub use sp_ark_models::pedersen::{commit, verify_opening};
+
+/// Generators of the commitments.
+pub type Generators<H> = pedersen::Generators<G1Projective<H>>;
+/// Pedersen commitment.
+pub type Commitment<H> = pedersen::Commitment<G1Projective<H>>;
@@ -0,0 +1,10 @@
+//! Bases of G1 registered with the host, see [`sp_ark_models::registry`].
+
+use sp_ark_models::registry;
+
+use crate::{g1, G1Projective};
+
+pub use sp_ark_models::registry::{BasesRegistry, Handle};
+
+/// Bases of G1 registered with the host.
+pub type RegisteredBases<H> = registry::RegisteredBases<G1Projective<H>, g1::Config<H>>;
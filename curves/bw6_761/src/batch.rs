@@ -0,0 +1,182 @@
+//! Batches of independent host calls, see [`sp_ark_models::batch`].
+
+use ark_ff::PrimeField;
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_std::{marker::PhantomData, vec::Vec};
+use codec::{Decode, Encode};
+use derivative::Derivative;
+use sp_ark_models::{
+    batch::Batch,
+    bw6::{G1Prepared, G2Prepared},
+    pairing::PairingOutput,
+    torus, HostCallError,
+};
+
+use crate::{
+    ArkScale, Config, Fr, G1Affine, G1Projective, G2Affine, G2Projective, HostFunctions, BW6_761,
+};
+
+/// Opcode of [`HostFunctions::bw6_761_mul_projective_g1`].
+pub const MUL_G1: u8 = 0;
+/// Opcode of [`HostFunctions::bw6_761_mul_projective_g2`].
+pub const MUL_G2: u8 = 1;
+/// Opcode of [`HostFunctions::bw6_761_msm_g1`].
+pub const MSM_G1: u8 = 2;
+/// Opcode of [`HostFunctions::bw6_761_msm_g2`].
+pub const MSM_G2: u8 = 3;
+/// Opcode of a multi Miller loop followed by a final exponentiation.
+pub const MULTI_PAIRING: u8 = 4;
+
+/// Compute the batched host call `op` with the host functions `H`.
+pub fn dispatch<H: HostFunctions>(op: u8, a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+    match op {
+        MUL_G1 => H::bw6_761_mul_projective_g1(a, b),
+        MUL_G2 => H::bw6_761_mul_projective_g2(a, b),
+        MSM_G1 => H::bw6_761_msm_g1(a, b),
+        MSM_G2 => H::bw6_761_msm_g2(a, b),
+        MULTI_PAIRING => {
+            H::bw6_761_multi_miller_loop(a, b).and_then(H::bw6_761_final_exponentiation)
+        }
+        _ => Err(()),
+    }
+}
+
+/// Result of a batched operation.
+#[allow(clippy::large_enum_variant)]
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Copy(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = ""),
+    Debug(bound = "")
+)]
+pub enum Output<H: HostFunctions> {
+    G1(G1Projective<H>),
+    G2(G2Projective<H>),
+    Gt(PairingOutput<BW6_761<H>>),
+}
+
+/// Builder of a batch of independent operations, computed with a single host call.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Default(bound = ""), Debug(bound = ""))]
+pub struct HostBatch<H: HostFunctions> {
+    batch: Batch,
+    ops: Vec<u8>,
+    _host: PhantomData<fn() -> H>,
+}
+
+impl<H: HostFunctions> HostBatch<H> {
+    /// Empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of queued operations.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Whether no operation is queued.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    fn push(&mut self, op: u8, a: Vec<u8>, b: Vec<u8>) -> &mut Self {
+        self.batch.push(op, a, b);
+        self.ops.push(op);
+        self
+    }
+
+    /// Queue the multiplication of `base` by `scalar` in G1.
+    pub fn mul_g1(&mut self, base: &G1Projective<H>, scalar: &Fr) -> &mut Self {
+        let base: ArkScaleProjective<G1Projective<H>> = (*base).into();
+        let scalar = scalar.into_bigint();
+        let scalar: ArkScale<&[u64]> = scalar.as_ref().into();
+        self.push(MUL_G1, base.encode(), scalar.encode())
+    }
+
+    /// Queue the multiplication of `base` by `scalar` in G2.
+    pub fn mul_g2(&mut self, base: &G2Projective<H>, scalar: &Fr) -> &mut Self {
+        let base: ArkScaleProjective<G2Projective<H>> = (*base).into();
+        let scalar = scalar.into_bigint();
+        let scalar: ArkScale<&[u64]> = scalar.as_ref().into();
+        self.push(MUL_G2, base.encode(), scalar.encode())
+    }
+
+    /// Queue the multi scalar multiplication of `bases` by `scalars` in G1.
+    pub fn msm_g1(
+        &mut self,
+        bases: &[G1Affine<H>],
+        scalars: &[Fr],
+    ) -> Result<&mut Self, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[G1Affine<H>]> = bases.into();
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+        Ok(self.push(MSM_G1, bases.encode(), scalars.encode()))
+    }
+
+    /// Queue the multi scalar multiplication of `bases` by `scalars` in G2.
+    pub fn msm_g2(
+        &mut self,
+        bases: &[G2Affine<H>],
+        scalars: &[Fr],
+    ) -> Result<&mut Self, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[G2Affine<H>]> = bases.into();
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+        Ok(self.push(MSM_G2, bases.encode(), scalars.encode()))
+    }
+
+    /// Queue the product of the pairings of `a` and `b`.
+    pub fn multi_pairing(
+        &mut self,
+        a: &[G1Affine<H>],
+        b: &[G2Affine<H>],
+    ) -> Result<&mut Self, HostCallError> {
+        if a.len() != b.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let a = a
+            .iter()
+            .map(|p| G1Prepared::<Config<H>>::from(*p))
+            .collect::<Vec<_>>();
+        let b = b
+            .iter()
+            .map(|q| G2Prepared::<Config<H>>::from(*q))
+            .collect::<Vec<_>>();
+        let a: ArkScale<Vec<G1Prepared<Config<H>>>> = a.into();
+        let b: ArkScale<Vec<G2Prepared<Config<H>>>> = b.into();
+        Ok(self.push(MULTI_PAIRING, a.encode(), b.encode()))
+    }
+
+    /// Compute the queued operations with a single host call, or one host call per
+    /// operation if the host does not provide batches or rejects the batch, returning
+    /// their results in order. See [`Batch::execute`] for the operations computed
+    /// twice.
+    pub fn execute(&self) -> Result<Vec<Output<H>>, HostCallError> {
+        let results = self.batch.execute(H::bw6_761_batch, dispatch::<H>)?;
+        self.ops
+            .iter()
+            .zip(results)
+            .map(|(op, result)| match *op {
+                MUL_G1 | MSM_G1 => decode_projective(&result).map(Output::G1),
+                MUL_G2 | MSM_G2 => decode_projective(&result).map(Output::G2),
+                _ => torus::decode_host_output(&result).map(Output::Gt),
+            })
+            .collect()
+    }
+}
+
+fn decode_projective<T>(result: &[u8]) -> Result<T, HostCallError>
+where
+    ArkScaleProjective<T>: Decode,
+{
+    let result = <ArkScaleProjective<T> as Decode>::decode(&mut &result[..])
+        .map_err(|_| HostCallError::DecodeFailed)?;
+    Ok(result.0)
+}
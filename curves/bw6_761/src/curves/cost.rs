@@ -0,0 +1,27 @@
+use sp_ark_models::{
+    cost::{CostModel, LinearCost},
+    metering::Operation,
+};
+
+/// [`CostModel`] of the BW6-761 host calls, seeded from the benchmark figures of
+/// the host calls performed by a Substrate runtime published in the README.
+///
+/// The MSM costs are fitted to the 10 and 1000 arguments figures.
+/// The pairing figure is split between the Miller loop and the final exponentiation
+/// in the same proportion as for BLS12-381.
+pub struct HostCostModel;
+
+impl CostModel for HostCostModel {
+    fn cost_of(op: Operation) -> Option<LinearCost> {
+        match op {
+            Operation::MultiMillerLoop => Some(LinearCost::new(0, 5_318_925)),
+            Operation::FinalExponentiation => Some(LinearCost::new(1_472_345, 0)),
+            Operation::PairingCheck => Some(LinearCost::new(1_472_345, 5_318_925)),
+            Operation::MsmG1 => Some(LinearCost::new(4_166_018, 139_351)),
+            Operation::MsmG2 => Some(LinearCost::new(3_474_831, 139_963)),
+            Operation::MulProjectiveG1 => Some(LinearCost::new(223_570, 0)),
+            Operation::MulProjectiveG2 => Some(LinearCost::new(308_600, 0)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use ark_std::{marker::PhantomData, vec::Vec};
+use sp_ark_models::{batch, software};
+
+use crate::HostFunctions;
+
+/// [`HostFunctions`] adapter computing each operation with the upstream arkworks
+/// implementation whenever the wrapped host call fails.
+///
+/// With the `software` feature enabled the wrapped host calls are never made.
+pub struct Fallback<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> HostFunctions for Fallback<H> {
+    fn bw6_761_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (a.as_slice(), b.as_slice()),
+            |(a, b)| H::bw6_761_multi_miller_loop(a.to_vec(), b.to_vec()),
+            |(a, b)| software::multi_miller_loop::<ark_bw6_761::BW6_761>(a, b),
+        )
+    }
+
+    fn bw6_761_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            f12.as_slice(),
+            |f12| H::bw6_761_final_exponentiation(f12.to_vec()),
+            software::final_exponentiation::<ark_bw6_761::BW6_761>,
+        )
+    }
+
+    fn bw6_761_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        // Without a host pairing check, `multi_pairing_is_one` falls back to the Miller
+        // loop and final exponentiation, preferring the host to software for each.
+        if cfg!(feature = "software") {
+            return software::pairing_check::<ark_bw6_761::BW6_761>(&a, &b);
+        }
+        H::bw6_761_pairing_check(a, b)
+    }
+
+    fn bw6_761_msm_g1(bases: Vec<u8>, bigints: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), bigints.as_slice()),
+            |(bases, bigints)| H::bw6_761_msm_g1(bases.to_vec(), bigints.to_vec()),
+            |(bases, bigints)| software::msm_sw::<ark_bw6_761::g1::Config>(bases, bigints),
+        )
+    }
+
+    fn bw6_761_msm_g2(bases: Vec<u8>, bigints: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), bigints.as_slice()),
+            |(bases, bigints)| H::bw6_761_msm_g2(bases.to_vec(), bigints.to_vec()),
+            |(bases, bigints)| software::msm_sw::<ark_bw6_761::g2::Config>(bases, bigints),
+        )
+    }
+
+    fn bw6_761_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bw6_761_mul_projective_g1(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_projective_sw::<ark_bw6_761::g1::Config>(base, scalar),
+        )
+    }
+
+    fn bw6_761_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bw6_761_mul_projective_g2(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_projective_sw::<ark_bw6_761::g2::Config>(base, scalar),
+        )
+    }
+
+    fn bw6_761_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::bw6_761_mul_gt(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| software::mul_gt::<ark_bw6_761::BW6_761>(base, scalar),
+        )
+    }
+
+    fn bw6_761_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bw6_761_msm_gt(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_gt::<ark_bw6_761::BW6_761>(bases, scalars),
+        )
+    }
+
+    fn bw6_761_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bw6_761_msm_small_g1(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_small_sw::<ark_bw6_761::g1::Config>(bases, scalars),
+        )
+    }
+
+    fn bw6_761_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::bw6_761_msm_small_g2(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| software::msm_small_sw::<ark_bw6_761::g2::Config>(bases, scalars),
+        )
+    }
+
+    fn bw6_761_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            calls.as_slice(),
+            |calls| H::bw6_761_batch(calls.to_vec()),
+            |calls| batch::execute(calls, crate::batch::dispatch::<Self>),
+        )
+    }
+
+    fn bw6_761_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            points.as_slice(),
+            |points| H::bw6_761_normalize_g1(points.to_vec()),
+            software::normalize_sw::<ark_bw6_761::g1::Config>,
+        )
+    }
+
+    fn bw6_761_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            points.as_slice(),
+            |points| H::bw6_761_normalize_g2(points.to_vec()),
+            software::normalize_sw::<ark_bw6_761::g2::Config>,
+        )
+    }
+
+    fn bw6_761_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            compressed.as_slice(),
+            |compressed| H::bw6_761_deserialize_g1(compressed.to_vec()),
+            software::deserialize_batch::<ark_bw6_761::G1Affine>,
+        )
+    }
+
+    fn bw6_761_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            compressed.as_slice(),
+            |compressed| H::bw6_761_deserialize_g2(compressed.to_vec()),
+            software::deserialize_batch::<ark_bw6_761::G2Affine>,
+        )
+    }
+}
+
+/// [`software::with_fallback`], never calling the host with the `software` feature
+/// of this crate enabled.
+fn with_fallback<A: Copy, R>(
+    args: A,
+    host: impl FnOnce(A) -> Result<R, ()>,
+    fallback: impl FnOnce(A) -> Result<R, ()>,
+) -> Result<R, ()> {
+    software::with_fallback(cfg!(feature = "software"), args, host, fallback)
+}
@@ -4,7 +4,10 @@ use ark_std::marker::PhantomData;
 use codec::{Decode, Encode};
 use sp_ark_models::{
     bw6,
+    msm::SWTryMsm,
     short_weierstrass::{Affine, Projective},
+    small_msm::SmallMsm,
+    software, HostCallError, VariableBaseMSM,
     {short_weierstrass::SWCurveConfig, CurveConfig},
 };
 
@@ -13,6 +16,10 @@ use crate::{ArkScale, Fq, Fr, HostFunctions};
 pub type G1Affine<H> = bw6::G1Affine<crate::Config<H>>;
 pub type G1Projective<H> = bw6::G1Projective<crate::Config<H>>;
 
+/// Precomputed multiples of a fixed point of G1, such as the generator, see
+/// [`sp_ark_models::fixed_base`].
+pub type FixedBase<H> = sp_ark_models::fixed_base::FixedBase<G1Projective<H>>;
+
 #[derive(Clone, Default, PartialEq, Eq)]
 
 pub struct Config<H: HostFunctions>(PhantomData<fn() -> H>);
@@ -38,6 +45,72 @@ impl<H: HostFunctions> CurveConfig for Config<H> {
     const COFACTOR_INV: Fr = MontFp!("91141326767669940707819291241958318717982251277713150053234367522357946997763584490607453720072232540829942217804");
 }
 
+impl<H: HostFunctions> Config<H> {
+    /// Fallible version of [`SWCurveConfig::msm`], surfacing host call errors.
+    pub fn try_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
+
+        let result = H::bw6_761_msm_g1(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`SWCurveConfig::mul_projective`], surfacing host call errors.
+    pub fn try_mul(
+        base: &Projective<Self>,
+        scalar: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result = H::bw6_761_mul_projective_g1(base.encode(), scalar.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> SWTryMsm for Config<H> {
+    fn try_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        Self::try_msm(bases, scalars)
+    }
+}
+
+impl<H: HostFunctions> SmallMsm<Affine<Self>> for Config<H> {
+    fn try_msm_small(
+        bases: &[Affine<Self>],
+        limbs: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let limbs: ArkScale<&[u64]> = limbs.into();
+
+        let result = H::bw6_761_msm_small_g1(bases.encode(), limbs.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
 impl<H: HostFunctions> SWCurveConfig for Config<H> {
     /// COEFF_A = 0
     const COEFF_A: Fq = Fq::ZERO;
@@ -57,37 +130,23 @@ impl<H: HostFunctions> SWCurveConfig for Config<H> {
         bases: &[Affine<Self>],
         scalars: &[<Self as CurveConfig>::ScalarField],
     ) -> Result<Projective<Self>, usize> {
-        let bases: ArkScale<&[Affine<Self>]> = bases.into();
-        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
-
-        let result = H::bw6_761_msm_g1(bases.encode(), scalars.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.map_err(|_| 0).map(|res| res.0)
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        // Host call errors fall back to software, `SWTryMsm::try_msm` surfaces them.
+        Ok(Self::try_msm(bases, scalars)
+            .unwrap_or_else(|_| VariableBaseMSM::msm_unchecked(bases, scalars)))
     }
 
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::bw6_761_mul_projective_g1(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(base, scalar).unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 
     fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: Projective<Self> = (*base).into();
-        let base: ArkScaleProjective<Projective<Self>> = base.into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::bw6_761_mul_projective_g1(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(&(*base).into(), scalar)
+            .unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 }
 
@@ -0,0 +1,167 @@
+use ark_std::{marker::PhantomData, vec::Vec};
+use sp_ark_models::{
+    batch::batch_len,
+    metering::{sequence_len, Meter, Meters, Operation, Usage},
+};
+
+use crate::HostFunctions;
+
+static METERS: Meters = Meters::new();
+
+fn meter<H: HostFunctions>() -> &'static Meter {
+    METERS.get::<H>()
+}
+
+/// [`HostFunctions`] wrapper recording the usage of every host call made through it.
+///
+/// The usage is recorded separately for every `H`.
+pub struct Metered<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> Metered<H> {
+    /// Usage recorded for `op`.
+    pub fn usage(op: Operation) -> Usage {
+        meter::<H>().usage(op)
+    }
+
+    /// Reset the usage recorded for every operation.
+    pub fn reset() {
+        meter::<H>().reset()
+    }
+}
+
+impl<H: HostFunctions> HostFunctions for Metered<H> {
+    fn bw6_761_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MultiMillerLoop,
+            a.len() + b.len(),
+            sequence_len(&a),
+            || H::bw6_761_multi_miller_loop(a, b),
+        )
+    }
+
+    fn bw6_761_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(Operation::FinalExponentiation, f12.len(), 1, || {
+            H::bw6_761_final_exponentiation(f12)
+        })
+    }
+
+    fn bw6_761_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        meter::<H>().call(
+            Operation::PairingCheck,
+            a.len() + b.len(),
+            sequence_len(&a),
+            || H::bw6_761_pairing_check(a, b),
+        )
+    }
+
+    fn bw6_761_msm_g1(bases: Vec<u8>, bigints: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmG1,
+            bases.len() + bigints.len(),
+            sequence_len(&bases),
+            || H::bw6_761_msm_g1(bases, bigints),
+        )
+    }
+
+    fn bw6_761_msm_g2(bases: Vec<u8>, bigints: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmG2,
+            bases.len() + bigints.len(),
+            sequence_len(&bases),
+            || H::bw6_761_msm_g2(bases, bigints),
+        )
+    }
+
+    fn bw6_761_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MulProjectiveG1,
+            base.len() + scalar.len(),
+            1,
+            || H::bw6_761_mul_projective_g1(base, scalar),
+        )
+    }
+
+    fn bw6_761_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MulProjectiveG2,
+            base.len() + scalar.len(),
+            1,
+            || H::bw6_761_mul_projective_g2(base, scalar),
+        )
+    }
+
+    fn bw6_761_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(Operation::MulGt, base.len() + scalar.len(), 1, || {
+            H::bw6_761_mul_gt(base, scalar)
+        })
+    }
+
+    fn bw6_761_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmGt,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bw6_761_msm_gt(bases, scalars),
+        )
+    }
+
+    fn bw6_761_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmSmall,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bw6_761_msm_small_g1(bases, scalars),
+        )
+    }
+
+    fn bw6_761_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmSmall,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::bw6_761_msm_small_g2(bases, scalars),
+        )
+    }
+
+    fn bw6_761_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(Operation::Batch, calls.len(), batch_len(&calls), || {
+            H::bw6_761_batch(calls)
+        })
+    }
+
+    fn bw6_761_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Normalize,
+            points.len(),
+            sequence_len(&points),
+            || H::bw6_761_normalize_g1(points),
+        )
+    }
+
+    fn bw6_761_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Normalize,
+            points.len(),
+            sequence_len(&points),
+            || H::bw6_761_normalize_g2(points),
+        )
+    }
+
+    fn bw6_761_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Deserialize,
+            compressed.len(),
+            sequence_len(&compressed),
+            || H::bw6_761_deserialize_g1(compressed),
+        )
+    }
+
+    fn bw6_761_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::Deserialize,
+            compressed.len(),
+            sequence_len(&compressed),
+            || H::bw6_761_deserialize_g2(compressed),
+        )
+    }
+}
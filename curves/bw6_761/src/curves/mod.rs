@@ -1,14 +1,20 @@
 use crate::{Fq, Fq3Config, Fq6Config};
 use ark_ff::{biginteger::BigInteger768 as BigInteger, BigInt};
-use ark_std::{marker::PhantomData, vec::Vec};
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_serialize::{CanonicalDeserialize, SerializationError};
+use ark_std::{marker::PhantomData, vec::Vec, Zero};
 use codec::{Decode, Encode};
 use sp_ark_models::{
     bw6::{BW6Config, G1Prepared, G2Prepared, TwistType, BW6},
     pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    software, torus, CurveGroup, Group, HostCallError, VariableBaseMSM,
 };
 
+mod cost;
+mod fallback;
 pub mod g1;
 pub mod g2;
+mod metered;
 
 const HOST_CALL: ark_scale::Usage = ark_scale::HOST_CALL;
 pub type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
@@ -17,8 +23,11 @@ pub type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
 mod tests;
 
 pub use self::{
+    cost::HostCostModel,
+    fallback::Fallback,
     g1::{G1Affine, G1Projective},
     g2::{G2Affine, G2Projective},
+    metered::Metered,
 };
 
 #[derive(PartialEq, Eq)]
@@ -31,6 +40,250 @@ pub trait HostFunctions: 'static {
     fn bw6_761_msm_g2(bases: Vec<u8>, bigints: Vec<u8>) -> Result<Vec<u8>, ()>;
     fn bw6_761_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()>;
     fn bw6_761_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()>;
+
+    /// Provided for hosts without a pairing check, which is then computed with a
+    /// Miller loop and a final exponentiation.
+    fn bw6_761_pairing_check(_a: Vec<u8>, _b: Vec<u8>) -> Result<bool, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without target group arithmetic, which can be
+    /// wrapped in [`Fallback`] to compute it in software.
+    fn bw6_761_mul_gt(_base: Vec<u8>, _scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without target group arithmetic, which can be
+    /// wrapped in [`Fallback`] to compute it in software.
+    fn bw6_761_msm_gt(_bases: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without small scalar MSMs, in which case
+    /// [`SmallMsm`](sp_ark_models::small_msm::SmallMsm) computes plain MSMs.
+    fn bw6_761_msm_small_g1(_bases: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without small scalar MSMs, in which case
+    /// [`SmallMsm`](sp_ark_models::small_msm::SmallMsm) computes plain MSMs.
+    fn bw6_761_msm_small_g2(_bases: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batches, in which case
+    /// [`HostBatch`](crate::batch::HostBatch) makes one host call per operation.
+    fn bw6_761_batch(_calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched normalisation, in which
+    /// case the inputs of the Miller loop are normalised in WASM.
+    fn bw6_761_normalize_g1(_points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched normalisation, in which
+    /// case the inputs of the Miller loop are normalised in WASM.
+    fn bw6_761_normalize_g2(_points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched deserialization, in which
+    /// case the points are decompressed and checked in WASM.
+    fn bw6_761_deserialize_g1(_compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batched deserialization, in which
+    /// case the points are decompressed and checked in WASM.
+    fn bw6_761_deserialize_g2(_compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+impl<H: HostFunctions> Config<H> {
+    /// Fallible version of [`BW6Config::multi_miller_loop`], surfacing host call errors.
+    pub fn try_multi_miller_loop(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> Result<MillerLoopOutput<BW6<Self>>, HostCallError> {
+        let a = a
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let b = b
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        if a.len() != b.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        Self::host_multi_miller_loop(&a, &b)
+    }
+
+    fn host_multi_miller_loop(
+        a: &[G1Prepared<Self>],
+        b: &[G2Prepared<Self>],
+    ) -> Result<MillerLoopOutput<BW6<Self>>, HostCallError> {
+        let a: ArkScale<&[G1Prepared<Self>]> = a.into();
+        let b: ArkScale<&[G2Prepared<Self>]> = b.into();
+
+        let result = H::bw6_761_multi_miller_loop(a.encode(), b.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        decode_miller_loop_output(result)
+    }
+
+    /// Software multi Miller loop, for when the host fails to compute it.
+    fn software_multi_miller_loop(
+        a: &[G1Prepared<Self>],
+        b: &[G2Prepared<Self>],
+    ) -> MillerLoopOutput<BW6<Self>> {
+        let a: ArkScale<&[G1Prepared<Self>]> = a.into();
+        let b: ArkScale<&[G2Prepared<Self>]> = b.into();
+
+        software::multi_miller_loop::<ark_bw6_761::BW6_761>(&a.encode(), &b.encode())
+            .map_err(|_| HostCallError::DecodeFailed)
+            .and_then(decode_miller_loop_output)
+            .expect("prepared points encode as affine points")
+    }
+
+    /// Fallible version of [`BW6Config::final_exponentiation`], surfacing host call errors.
+    pub fn try_final_exponentiation(
+        f: MillerLoopOutput<BW6<Self>>,
+    ) -> Result<PairingOutput<BW6<Self>>, HostCallError> {
+        let target: ArkScale<<BW6<Self> as Pairing>::TargetField> = f.0.into();
+
+        let result = H::bw6_761_final_exponentiation(target.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        torus::decode_host_output(&result)
+    }
+
+    /// Fallible version of [`BW6Config::multi_pairing_is_one`], surfacing host call errors.
+    pub fn try_multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> Result<bool, HostCallError> {
+        let a = a
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let b = b
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        if a.len() != b.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        Self::host_pairing_check(&a, &b)
+    }
+
+    fn host_pairing_check(
+        a: &[G1Prepared<Self>],
+        b: &[G2Prepared<Self>],
+    ) -> Result<bool, HostCallError> {
+        let a: ArkScale<&[G1Prepared<Self>]> = a.into();
+        let b: ArkScale<&[G2Prepared<Self>]> = b.into();
+
+        H::bw6_761_pairing_check(a.encode(), b.encode()).map_err(|_| HostCallError::HostRejected)
+    }
+
+    /// Fallible version of [`BW6Config::gt_mul`], surfacing host call errors.
+    pub fn try_gt_mul(
+        base: &PairingOutput<BW6<Self>>,
+        scalar: &[u64],
+    ) -> Result<PairingOutput<BW6<Self>>, HostCallError> {
+        let base: ArkScale<PairingOutput<BW6<Self>>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result = H::bw6_761_mul_gt(base.encode(), scalar.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        torus::decode_host_output(&result)
+    }
+
+    /// Fallible version of [`BW6Config::gt_msm`], surfacing host call errors.
+    pub fn try_gt_msm(
+        bases: &[PairingOutput<BW6<Self>>],
+        scalars: &[<BW6<Self> as Pairing>::ScalarField],
+    ) -> Result<PairingOutput<BW6<Self>>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[PairingOutput<BW6<Self>>]> = bases.into();
+        let scalars: ArkScale<&[<BW6<Self> as Pairing>::ScalarField]> = scalars.into();
+
+        let result = H::bw6_761_msm_gt(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        torus::decode_host_output(&result)
+    }
+
+    /// Fallible version of [`BW6Config::normalize_g1`], surfacing host call errors.
+    pub fn try_normalize_g1(points: &[G1Projective<H>]) -> Result<Vec<G1Affine<H>>, HostCallError> {
+        let mut encoded = (points.len() as u64).encode();
+        for p in points {
+            ArkScaleProjective::from(p).encode_to(&mut encoded);
+        }
+
+        let result = H::bw6_761_normalize_g1(encoded).map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G1Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        if result.0.len() != points.len() {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`BW6Config::normalize_g2`], surfacing host call errors.
+    pub fn try_normalize_g2(points: &[G2Projective<H>]) -> Result<Vec<G2Affine<H>>, HostCallError> {
+        let mut encoded = (points.len() as u64).encode();
+        for p in points {
+            ArkScaleProjective::from(p).encode_to(&mut encoded);
+        }
+
+        let result = H::bw6_761_normalize_g2(encoded).map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G2Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        if result.0.len() != points.len() {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`BW6Config::deserialize_g1_batch`], surfacing host call
+    /// errors.
+    pub fn try_deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<H>>, HostCallError> {
+        let result = H::bw6_761_deserialize_g1(compressed.to_vec())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G1Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        // The compressed encoding starts with the number of points.
+        if u64::decode(&mut &compressed[..]).ok() != Some(result.0.len() as u64) {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`BW6Config::deserialize_g2_batch`], surfacing host call
+    /// errors.
+    pub fn try_deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<H>>, HostCallError> {
+        let result = H::bw6_761_deserialize_g2(compressed.to_vec())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScale<Vec<G2Affine<H>>> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        // The compressed encoding starts with the number of points.
+        if u64::decode(&mut &compressed[..]).ok() != Some(result.0.len() as u64) {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(result.0)
+    }
 }
 
 impl<H: HostFunctions> BW6Config for Config<H> {
@@ -75,43 +328,92 @@ impl<H: HostFunctions> BW6Config for Config<H> {
         a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
         b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
     ) -> MillerLoopOutput<BW6<Self>> {
-        let a: ArkScale<Vec<<BW6<Self> as Pairing>::G1Prepared>> = a
+        let mut a = a
             .into_iter()
-            .map(|el| {
-                let el: <BW6<Self> as Pairing>::G1Prepared = el.into();
-                el
-            })
-            .collect::<Vec<_>>()
-            .into();
-        let b: ArkScale<Vec<<BW6<Self> as Pairing>::G2Prepared>> = b
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let mut b = b
             .into_iter()
-            .map(|el| {
-                let el: <BW6<Self> as Pairing>::G2Prepared = el.into();
-                el
-            })
-            .collect::<Vec<_>>()
-            .into();
-        let result = H::bw6_761_multi_miller_loop(a.encode(), b.encode()).unwrap();
-
-        let result = <ArkScale<<BW6<Self> as Pairing>::TargetField> as Decode>::decode(
-            &mut result.as_slice(),
-        )
-        .unwrap()
-        .0;
-        MillerLoopOutput(result)
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        // Like arkworks, pair the inputs up to the shorter of the two.
+        let len = a.len().min(b.len());
+        a.truncate(len);
+        b.truncate(len);
+        Self::host_multi_miller_loop(&a, &b)
+            .unwrap_or_else(|_| Self::software_multi_miller_loop(&a, &b))
     }
 
     fn final_exponentiation(f: MillerLoopOutput<BW6<Self>>) -> Option<PairingOutput<BW6<Self>>> {
-        let target: ArkScale<<BW6<Self> as Pairing>::TargetField> = f.0.into();
-
-        let result = H::bw6_761_final_exponentiation(target.encode());
+        Self::try_final_exponentiation(f).ok()
+    }
 
-        result.ok().map(|res| {
-            <ArkScale<PairingOutput<BW6<Self>>> as Decode>::decode(&mut res.as_slice())
-                .unwrap()
-                .0
+    fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> bool {
+        let a = a
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G1Prepared<Self>>>();
+        let b = b
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<G2Prepared<Self>>>();
+        if a.len() != b.len() {
+            return false;
+        }
+        // Hosts without a pairing check compute it with their Miller loop and final
+        // exponentiation.
+        Self::host_pairing_check(&a, &b).unwrap_or_else(|_| {
+            let f = Self::host_multi_miller_loop(&a, &b)
+                .unwrap_or_else(|_| Self::software_multi_miller_loop(&a, &b));
+            Self::try_final_exponentiation(f).is_ok_and(|f| f.is_zero())
         })
     }
+
+    fn gt_mul(base: &PairingOutput<BW6<Self>>, scalar: &[u64]) -> PairingOutput<BW6<Self>> {
+        Self::try_gt_mul(base, scalar).unwrap_or_else(|_| base.mul_bigint(scalar))
+    }
+
+    fn gt_msm(
+        bases: &[PairingOutput<BW6<Self>>],
+        scalars: &[<BW6<Self> as Pairing>::ScalarField],
+    ) -> Result<PairingOutput<BW6<Self>>, usize> {
+        match Self::try_gt_msm(bases, scalars) {
+            Ok(result) => Ok(result),
+            Err(_) => VariableBaseMSM::msm(bases, scalars),
+        }
+    }
+
+    fn normalize_g1(points: &[G1Projective<H>]) -> Vec<G1Affine<H>> {
+        Self::try_normalize_g1(points)
+            .unwrap_or_else(|_| G1Projective::<H>::normalize_batch(points))
+    }
+
+    fn normalize_g2(points: &[G2Projective<H>]) -> Vec<G2Affine<H>> {
+        Self::try_normalize_g2(points)
+            .unwrap_or_else(|_| G2Projective::<H>::normalize_batch(points))
+    }
+
+    fn deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<H>>, SerializationError> {
+        Self::try_deserialize_g1_batch(compressed)
+            .or_else(|_| Vec::deserialize_compressed(compressed))
+    }
+
+    fn deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<H>>, SerializationError> {
+        Self::try_deserialize_g2_batch(compressed)
+            .or_else(|_| Vec::deserialize_compressed(compressed))
+    }
+}
+
+fn decode_miller_loop_output<P: BW6Config>(
+    result: Vec<u8>,
+) -> Result<MillerLoopOutput<BW6<P>>, HostCallError> {
+    let result =
+        <ArkScale<<BW6<P> as Pairing>::TargetField> as Decode>::decode(&mut result.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+    Ok(MillerLoopOutput(result.0))
 }
 
 pub type BW6_761<H> = BW6<Config<H>>;
@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use crate::{
-    G1Projective as G1ProjectiveHost, G2Projective as G2ProjectiveHost, HostFunctions,
+    Fallback, G1Projective as G1ProjectiveHost, G2Projective as G2ProjectiveHost, HostFunctions,
     BW6_761 as BW6_761Host,
 };
 use ark_algebra_test_templates::*;
@@ -37,3 +37,54 @@ test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; sp_ark_models::pairing::PairingOutput<BW6_761>; msm);
 test_pairing!(pairing; super::BW6_761);
+
+struct Unavailable;
+
+impl HostFunctions for Unavailable {
+    fn bw6_761_multi_miller_loop(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bw6_761_final_exponentiation(_: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bw6_761_msm_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bw6_761_msm_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bw6_761_mul_projective_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bw6_761_mul_projective_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+type BW6_761Fallback = BW6_761Host<Fallback<Unavailable>>;
+type G1ProjectiveFallback = G1ProjectiveHost<Fallback<Unavailable>>;
+type G2ProjectiveFallback = G2ProjectiveHost<Fallback<Unavailable>>;
+
+test_group!(g1_fallback; G1ProjectiveFallback; sw);
+test_group!(g2_fallback; G2ProjectiveFallback; sw);
+test_pairing!(pairing_fallback; super::BW6_761Fallback);
+
+#[test]
+fn test_fallback_matches_host() {
+    use ark_std::UniformRand;
+    use sp_ark_models::{pairing::Pairing, AffineRepr, CurveGroup, Group};
+
+    let mut rng = ark_std::test_rng();
+    let s = <BW6_761 as Pairing>::ScalarField::rand(&mut rng);
+
+    let p = (G1Projective::generator() * s).into_affine();
+    let q = (G2Projective::generator() * s).into_affine();
+    let p_fallback = (G1ProjectiveFallback::generator() * s).into_affine();
+    let q_fallback = (G2ProjectiveFallback::generator() * s).into_affine();
+    assert_eq!(p.xy(), p_fallback.xy());
+    assert_eq!(q.xy(), q_fallback.xy());
+
+    let expected = BW6_761::pairing(p, q);
+    let result = BW6_761Fallback::pairing(p_fallback, q_fallback);
+    assert_eq!(expected.0, result.0);
+}
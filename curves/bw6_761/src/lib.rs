@@ -29,6 +29,7 @@
 //! * A = 0
 //! * B = 4
 
+pub mod batch;
 pub mod curves;
 
 pub use ark_bw6_761::{fq, fq::*, fq3, fq3::*, fq6, fq6::*, fr, fr::*};
@@ -0,0 +1,91 @@
+//! Batches of independent host calls, see [`sp_ark_models::batch`].
+
+use ark_ff::PrimeField;
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_std::{marker::PhantomData, vec::Vec};
+use codec::{Decode, Encode};
+use derivative::Derivative;
+use sp_ark_models::{batch::Batch, HostCallError};
+
+use crate::{curves::ArkScale, EdwardsAffine, EdwardsProjective, Fr, HostFunctions};
+
+/// Opcode of [`HostFunctions::ed_on_bls12_377_mul_projective`].
+pub const MUL: u8 = 0;
+/// Opcode of [`HostFunctions::ed_on_bls12_377_msm`].
+pub const MSM: u8 = 1;
+
+/// Compute the batched host call `op` with the host functions `H`.
+pub fn dispatch<H: HostFunctions>(op: u8, a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+    match op {
+        MUL => H::ed_on_bls12_377_mul_projective(a, b),
+        MSM => H::ed_on_bls12_377_msm(a, b),
+        _ => Err(()),
+    }
+}
+
+/// Builder of a batch of independent operations, computed with a single host call.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Default(bound = ""), Debug(bound = ""))]
+pub struct HostBatch<H: HostFunctions> {
+    batch: Batch,
+    _host: PhantomData<fn() -> H>,
+}
+
+impl<H: HostFunctions> HostBatch<H> {
+    /// Empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of queued operations.
+    pub fn len(&self) -> usize {
+        self.batch.len()
+    }
+
+    /// Whether no operation is queued.
+    pub fn is_empty(&self) -> bool {
+        self.batch.is_empty()
+    }
+
+    /// Queue the multiplication of `base` by `scalar`.
+    pub fn mul(&mut self, base: &EdwardsProjective<H>, scalar: &Fr) -> &mut Self {
+        let base: ArkScaleProjective<EdwardsProjective<H>> = (*base).into();
+        let scalar = scalar.into_bigint();
+        let scalar: ArkScale<&[u64]> = scalar.as_ref().into();
+        self.batch.push(MUL, base.encode(), scalar.encode());
+        self
+    }
+
+    /// Queue the multi scalar multiplication of `bases` by `scalars`.
+    pub fn msm(
+        &mut self,
+        bases: &[EdwardsAffine<H>],
+        scalars: &[Fr],
+    ) -> Result<&mut Self, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[EdwardsAffine<H>]> = bases.into();
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+        self.batch.push(MSM, bases.encode(), scalars.encode());
+        Ok(self)
+    }
+
+    /// Compute the queued operations with a single host call, or one host call per
+    /// operation if the host does not provide batches or rejects the batch, returning
+    /// their results in order. See [`Batch::execute`] for the operations computed
+    /// twice.
+    pub fn execute(&self) -> Result<Vec<EdwardsProjective<H>>, HostCallError> {
+        self.batch
+            .execute(H::ed_on_bls12_377_batch, dispatch::<H>)?
+            .iter()
+            .map(|result| {
+                let result = <ArkScaleProjective<EdwardsProjective<H>> as Decode>::decode(
+                    &mut result.as_slice(),
+                )
+                .map_err(|_| HostCallError::DecodeFailed)?;
+                Ok(result.0)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,20 @@
+use sp_ark_models::{
+    cost::{CostModel, LinearCost},
+    metering::Operation,
+};
+
+/// [`CostModel`] of the Ed-on-BLS12-377 host calls, seeded from the benchmark figures of
+/// the host calls performed by a Substrate runtime published in the README.
+///
+/// The MSM costs are fitted to the 10 and 1000 arguments figures.
+pub struct HostCostModel;
+
+impl CostModel for HostCostModel {
+    fn cost_of(op: Operation) -> Option<LinearCost> {
+        match op {
+            Operation::TeMsm => Some(LinearCost::new(548_418, 15_209)),
+            Operation::TeMulProjective => Some(LinearCost::new(32_720, 0)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use ark_std::{marker::PhantomData, vec::Vec};
+use sp_ark_models::{batch, software};
+
+use crate::HostFunctions;
+
+/// [`HostFunctions`] adapter computing each operation with the upstream arkworks
+/// implementation whenever the wrapped host call fails.
+///
+/// With the `software` feature enabled the wrapped host calls are never made.
+pub struct Fallback<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> HostFunctions for Fallback<H> {
+    fn ed_on_bls12_377_msm(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::ed_on_bls12_377_msm(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| {
+                software::msm_te::<ark_ed_on_bls12_377::EdwardsConfig>(bases, scalars)
+            },
+        )
+    }
+
+    fn ed_on_bls12_377_mul_projective(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| H::ed_on_bls12_377_mul_projective(base.to_vec(), scalar.to_vec()),
+            |(base, scalar)| {
+                software::mul_projective_te::<ark_ed_on_bls12_377::EdwardsConfig>(base, scalar)
+            },
+        )
+    }
+
+    fn ed_on_bls12_377_register_bases(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        // Software keeps no registry, leaving the bases to plain MSMs.
+        if cfg!(feature = "software") {
+            return Err(());
+        }
+        H::ed_on_bls12_377_register_bases(bases)
+    }
+
+    fn ed_on_bls12_377_msm_with_handle(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        if cfg!(feature = "software") {
+            return Err(());
+        }
+        H::ed_on_bls12_377_msm_with_handle(handle, scalars)
+    }
+
+    fn ed_on_bls12_377_msm_small(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| H::ed_on_bls12_377_msm_small(bases.to_vec(), scalars.to_vec()),
+            |(bases, scalars)| {
+                software::msm_small_te::<ark_ed_on_bls12_377::EdwardsConfig>(bases, scalars)
+            },
+        )
+    }
+
+    fn ed_on_bls12_377_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            calls.as_slice(),
+            |calls| H::ed_on_bls12_377_batch(calls.to_vec()),
+            |calls| batch::execute(calls, crate::batch::dispatch::<Self>),
+        )
+    }
+}
+
+/// [`software::with_fallback`], never calling the host with the `software` feature
+/// of this crate enabled.
+fn with_fallback<A: Copy, R>(
+    args: A,
+    host: impl FnOnce(A) -> Result<R, ()>,
+    fallback: impl FnOnce(A) -> Result<R, ()>,
+) -> Result<R, ()> {
+    software::with_fallback(cfg!(feature = "software"), args, host, fallback)
+}
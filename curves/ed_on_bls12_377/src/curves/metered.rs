@@ -0,0 +1,83 @@
+use ark_std::{marker::PhantomData, vec::Vec};
+use sp_ark_models::{
+    batch::batch_len,
+    metering::{sequence_len, Meter, Meters, Operation, Usage},
+};
+
+use crate::HostFunctions;
+
+static METERS: Meters = Meters::new();
+
+fn meter<H: HostFunctions>() -> &'static Meter {
+    METERS.get::<H>()
+}
+
+/// [`HostFunctions`] wrapper recording the usage of every host call made through it.
+///
+/// The usage is recorded separately for every `H`.
+pub struct Metered<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> Metered<H> {
+    /// Usage recorded for `op`.
+    pub fn usage(op: Operation) -> Usage {
+        meter::<H>().usage(op)
+    }
+
+    /// Reset the usage recorded for every operation.
+    pub fn reset() {
+        meter::<H>().reset()
+    }
+}
+
+impl<H: HostFunctions> HostFunctions for Metered<H> {
+    fn ed_on_bls12_377_msm(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::TeMsm,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::ed_on_bls12_377_msm(bases, scalars),
+        )
+    }
+
+    fn ed_on_bls12_377_mul_projective(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::TeMulProjective,
+            base.len() + scalar.len(),
+            1,
+            || H::ed_on_bls12_377_mul_projective(base, scalar),
+        )
+    }
+
+    fn ed_on_bls12_377_register_bases(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::RegisterBases,
+            bases.len(),
+            sequence_len(&bases),
+            || H::ed_on_bls12_377_register_bases(bases),
+        )
+    }
+
+    fn ed_on_bls12_377_msm_with_handle(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmWithHandle,
+            handle.len() + scalars.len(),
+            sequence_len(&scalars),
+            || H::ed_on_bls12_377_msm_with_handle(handle, scalars),
+        )
+    }
+
+    fn ed_on_bls12_377_msm_small(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(
+            Operation::MsmSmall,
+            bases.len() + scalars.len(),
+            sequence_len(&bases),
+            || H::ed_on_bls12_377_msm_small(bases, scalars),
+        )
+    }
+
+    fn ed_on_bls12_377_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        meter::<H>().call(Operation::Batch, calls.len(), batch_len(&calls), || {
+            H::ed_on_bls12_377_batch(calls)
+        })
+    }
+}
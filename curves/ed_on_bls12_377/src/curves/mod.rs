@@ -3,26 +3,63 @@ use ark_scale::hazmat::ArkScaleProjective;
 use ark_std::{marker::PhantomData, vec::Vec};
 use codec::{Decode, Encode};
 use sp_ark_models::{
+    msm::TETryMsm,
+    registry::{BasesRegistry, Handle},
+    small_msm::SmallMsm,
+    software,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
-    CurveConfig,
+    CurveConfig, HostCallError, VariableBaseMSM,
 };
 
 use crate::{fq::Fq, fr::Fr};
 
 const HOST_CALL: ark_scale::Usage = ark_scale::HOST_CALL;
-type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
+pub(crate) type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
 
+mod cost;
+mod fallback;
+mod metered;
 #[cfg(test)]
 mod tests;
 
+pub use self::{cost::HostCostModel, fallback::Fallback, metered::Metered};
+
 pub type EdwardsAffine<H> = Affine<EdwardsConfig<H>>;
 pub type EdwardsProjective<H> = Projective<EdwardsConfig<H>>;
 
+/// Precomputed multiples of a fixed point, such as the generator, see
+/// [`sp_ark_models::fixed_base`].
+pub type FixedBase<H> = sp_ark_models::fixed_base::FixedBase<EdwardsProjective<H>>;
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsConfig<H: HostFunctions>(PhantomData<fn() -> H>);
 pub trait HostFunctions: 'static {
     fn ed_on_bls12_377_msm(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()>;
     fn ed_on_bls12_377_mul_projective(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()>;
+
+    /// Provided as unavailable for hosts without a registry of bases, in which case
+    /// [`RegisteredBases`](crate::registry::RegisteredBases) computes plain MSMs.
+    fn ed_on_bls12_377_register_bases(_bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without a registry of bases, in which case
+    /// [`RegisteredBases`](crate::registry::RegisteredBases) computes plain MSMs.
+    fn ed_on_bls12_377_msm_with_handle(_handle: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without small scalar MSMs, in which case
+    /// [`SmallMsm`](sp_ark_models::small_msm::SmallMsm) computes plain MSMs.
+    fn ed_on_bls12_377_msm_small(_bases: Vec<u8>, _scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batches, in which case
+    /// [`HostBatch`](crate::batch::HostBatch) makes one host call per operation.
+    fn ed_on_bls12_377_batch(_calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
 }
 
 impl<H: HostFunctions> CurveConfig for EdwardsConfig<H> {
@@ -38,6 +75,95 @@ impl<H: HostFunctions> CurveConfig for EdwardsConfig<H> {
         MontFp!("527778859339273151515551558673846658209717731602102048798421311598680340096");
 }
 
+impl<H: HostFunctions> EdwardsConfig<H> {
+    /// Fallible version of [`TECurveConfig::msm`], surfacing host call errors.
+    pub fn try_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
+
+        let result = H::ed_on_bls12_377_msm(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`TECurveConfig::mul_projective`], surfacing host call errors.
+    pub fn try_mul(
+        base: &Projective<Self>,
+        scalar: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result = H::ed_on_bls12_377_mul_projective(base.encode(), scalar.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> BasesRegistry<Projective<Self>> for EdwardsConfig<H> {
+    fn register_bases(bases: &[Affine<Self>]) -> Result<Handle, HostCallError> {
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+
+        let result = H::ed_on_bls12_377_register_bases(bases.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        Handle::try_from(result.as_slice()).map_err(|_| HostCallError::DecodeFailed)
+    }
+
+    fn msm_with_handle(handle: &Handle, scalars: &[Fr]) -> Result<Projective<Self>, HostCallError> {
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+
+        let result = H::ed_on_bls12_377_msm_with_handle(handle.to_vec(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> TETryMsm for EdwardsConfig<H> {
+    fn try_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        Self::try_msm(bases, scalars)
+    }
+}
+
+impl<H: HostFunctions> SmallMsm<Affine<Self>> for EdwardsConfig<H> {
+    fn try_msm_small(
+        bases: &[Affine<Self>],
+        limbs: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let limbs: ArkScale<&[u64]> = limbs.into();
+
+        let result = H::ed_on_bls12_377_msm_small(bases.encode(), limbs.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
 impl<H: HostFunctions> TECurveConfig for EdwardsConfig<H> {
     /// COEFF_A = -1
     const COEFF_A: Fq = MontFp!("-1");
@@ -61,38 +187,23 @@ impl<H: HostFunctions> TECurveConfig for EdwardsConfig<H> {
         bases: &[Affine<Self>],
         scalars: &[<Self as CurveConfig>::ScalarField],
     ) -> Result<Projective<Self>, usize> {
-        let bases: ArkScale<&[Affine<Self>]> = bases.into();
-        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
-
-        let result = H::ed_on_bls12_377_msm(bases.encode(), scalars.encode()).unwrap();
-
-        let result = <ArkScaleProjective<Projective<EdwardsConfig<H>>> as Decode>::decode(
-            &mut result.as_slice(),
-        );
-        result.map_err(|_| 0).map(|res| res.0)
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        // Host call errors fall back to software, `TETryMsm::try_msm` surfaces them.
+        Ok(Self::try_msm(bases, scalars)
+            .unwrap_or_else(|_| VariableBaseMSM::msm_unchecked(bases, scalars)))
     }
 
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::ed_on_bls12_377_mul_projective(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(base, scalar).unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 
     fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: Projective<Self> = (*base).into();
-        let base: ArkScaleProjective<Projective<Self>> = base.into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result = H::ed_on_bls12_377_mul_projective(base.encode(), scalar.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_mul` surfaces them.
+        Self::try_mul(&(*base).into(), scalar)
+            .unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 }
 
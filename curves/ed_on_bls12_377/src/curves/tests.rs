@@ -1,5 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use crate::{EdwardsProjective as EdwardsProjectiveHost, HostFunctions};
+use crate::{EdwardsProjective as EdwardsProjectiveHost, Fallback, HostFunctions};
 use ark_algebra_test_templates::*;
 
 struct Host {}
@@ -15,3 +15,31 @@ impl HostFunctions for Host {
 
 type EdwardsProjective = EdwardsProjectiveHost<Host>;
 test_group!(te; super::EdwardsProjective; te);
+
+struct Unavailable;
+
+impl HostFunctions for Unavailable {
+    fn ed_on_bls12_377_msm(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn ed_on_bls12_377_mul_projective(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+type EdwardsProjectiveFallback = EdwardsProjectiveHost<Fallback<Unavailable>>;
+
+test_group!(te_fallback; EdwardsProjectiveFallback; te);
+
+#[test]
+fn test_fallback_matches_host() {
+    use ark_std::UniformRand;
+    use sp_ark_models::{AffineRepr, CurveGroup, Group};
+
+    let mut rng = ark_std::test_rng();
+    let s = crate::Fr::rand(&mut rng);
+
+    let p = (EdwardsProjective::generator() * s).into_affine();
+    let p_fallback = (EdwardsProjectiveFallback::generator() * s).into_affine();
+    assert_eq!(p.xy(), p_fallback.xy());
+}
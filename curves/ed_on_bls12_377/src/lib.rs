@@ -25,7 +25,11 @@
 
 #[cfg(feature = "r1cs")]
 pub use ark_ed_on_bls12_377::constraints::*;
+pub mod batch;
 pub mod curves;
+pub mod pedersen;
+pub mod registry;
+pub mod schnorr;
 
 pub use ark_ed_on_bls12_377::{fq, fq::*, fr, fr::*};
 pub use curves::*;
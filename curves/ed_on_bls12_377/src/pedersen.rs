@@ -0,0 +1,12 @@
+//! Pedersen vector commitments over the twisted Edwards curve over the BLS12-377 scalar field, see [`sp_ark_models::pedersen`].
+
+use sp_ark_models::pedersen;
+
+use crate::EdwardsProjective;
+
+pub use sp_ark_models::pedersen::{commit, verify_opening};
+
+/// Generators of the commitments.
+pub type Generators<H> = pedersen::Generators<EdwardsProjective<H>>;
+/// Pedersen commitment.
+pub type Commitment<H> = pedersen::Commitment<EdwardsProjective<H>>;
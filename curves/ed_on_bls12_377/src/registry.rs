@@ -0,0 +1,10 @@
+//! Bases of the curve registered with the host, see [`sp_ark_models::registry`].
+
+use sp_ark_models::registry;
+
+use crate::{EdwardsConfig, EdwardsProjective};
+
+pub use sp_ark_models::registry::{BasesRegistry, Handle};
+
+/// Bases of the curve registered with the host.
+pub type RegisteredBases<H> = registry::RegisteredBases<EdwardsProjective<H>, EdwardsConfig<H>>;
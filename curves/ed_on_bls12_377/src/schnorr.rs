@@ -0,0 +1,14 @@
+//! Schnorr signatures over the twisted Edwards curve over the BLS12-377 scalar field, see [`sp_ark_models::schnorr`].
+
+use sp_ark_models::schnorr;
+
+use crate::EdwardsProjective;
+
+pub use sp_ark_models::schnorr::{batch_verify, sign, verify};
+
+/// Schnorr secret key.
+pub type SecretKey<H> = schnorr::SecretKey<EdwardsProjective<H>>;
+/// Schnorr public key.
+pub type PublicKey<H> = schnorr::PublicKey<EdwardsProjective<H>>;
+/// Schnorr signature.
+pub type Signature<H> = schnorr::Signature<EdwardsProjective<H>>;
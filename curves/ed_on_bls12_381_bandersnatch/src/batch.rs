@@ -0,0 +1,151 @@
+//! Batches of independent host calls, see [`sp_ark_models::batch`].
+
+use ark_ff::PrimeField;
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_std::{marker::PhantomData, vec::Vec};
+use codec::{Decode, Encode};
+use derivative::Derivative;
+use sp_ark_models::{batch::Batch, HostCallError};
+
+use crate::{
+    curves::ArkScale, EdwardsAffine, EdwardsProjective, Fr, HostFunctions, SWAffine, SWProjective,
+};
+
+/// Opcode of [`HostFunctions::ed_on_bls12_381_bandersnatch_te_mul_projective`].
+pub const TE_MUL: u8 = 0;
+/// Opcode of [`HostFunctions::ed_on_bls12_381_bandersnatch_sw_mul_projective`].
+pub const SW_MUL: u8 = 1;
+/// Opcode of [`HostFunctions::ed_on_bls12_381_bandersnatch_te_msm`].
+pub const TE_MSM: u8 = 2;
+/// Opcode of [`HostFunctions::ed_on_bls12_381_bandersnatch_sw_msm`].
+pub const SW_MSM: u8 = 3;
+
+/// Compute the batched host call `op` with the host functions `H`.
+pub fn dispatch<H: HostFunctions>(op: u8, a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+    match op {
+        TE_MUL => H::ed_on_bls12_381_bandersnatch_te_mul_projective(a, b),
+        SW_MUL => H::ed_on_bls12_381_bandersnatch_sw_mul_projective(a, b),
+        TE_MSM => H::ed_on_bls12_381_bandersnatch_te_msm(a, b),
+        SW_MSM => H::ed_on_bls12_381_bandersnatch_sw_msm(a, b),
+        _ => Err(()),
+    }
+}
+
+/// Result of a batched operation.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Copy(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = ""),
+    Debug(bound = "")
+)]
+pub enum Output<H: HostFunctions> {
+    Te(EdwardsProjective<H>),
+    Sw(SWProjective<H>),
+}
+
+/// Builder of a batch of independent operations, computed with a single host call.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Default(bound = ""), Debug(bound = ""))]
+pub struct HostBatch<H: HostFunctions> {
+    batch: Batch,
+    ops: Vec<u8>,
+    _host: PhantomData<fn() -> H>,
+}
+
+impl<H: HostFunctions> HostBatch<H> {
+    /// Empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of queued operations.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Whether no operation is queued.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    fn push(&mut self, op: u8, a: Vec<u8>, b: Vec<u8>) -> &mut Self {
+        self.batch.push(op, a, b);
+        self.ops.push(op);
+        self
+    }
+
+    /// Queue the multiplication of `base` by `scalar` in the twisted Edwards form.
+    pub fn te_mul(&mut self, base: &EdwardsProjective<H>, scalar: &Fr) -> &mut Self {
+        let base: ArkScaleProjective<EdwardsProjective<H>> = (*base).into();
+        let scalar = scalar.into_bigint();
+        let scalar: ArkScale<&[u64]> = scalar.as_ref().into();
+        self.push(TE_MUL, base.encode(), scalar.encode())
+    }
+
+    /// Queue the multiplication of `base` by `scalar` in the short Weierstrass form.
+    pub fn sw_mul(&mut self, base: &SWProjective<H>, scalar: &Fr) -> &mut Self {
+        let base: ArkScaleProjective<SWProjective<H>> = (*base).into();
+        let scalar = scalar.into_bigint();
+        let scalar: ArkScale<&[u64]> = scalar.as_ref().into();
+        self.push(SW_MUL, base.encode(), scalar.encode())
+    }
+
+    /// Queue the multi scalar multiplication of `bases` by `scalars` in the twisted
+    /// Edwards form.
+    pub fn te_msm(
+        &mut self,
+        bases: &[EdwardsAffine<H>],
+        scalars: &[Fr],
+    ) -> Result<&mut Self, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[EdwardsAffine<H>]> = bases.into();
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+        Ok(self.push(TE_MSM, bases.encode(), scalars.encode()))
+    }
+
+    /// Queue the multi scalar multiplication of `bases` by `scalars` in the short
+    /// Weierstrass form.
+    pub fn sw_msm(
+        &mut self,
+        bases: &[SWAffine<H>],
+        scalars: &[Fr],
+    ) -> Result<&mut Self, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[SWAffine<H>]> = bases.into();
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+        Ok(self.push(SW_MSM, bases.encode(), scalars.encode()))
+    }
+
+    /// Compute the queued operations with a single host call, or one host call per
+    /// operation if the host does not provide batches or rejects the batch, returning
+    /// their results in order. See [`Batch::execute`] for the operations computed
+    /// twice.
+    pub fn execute(&self) -> Result<Vec<Output<H>>, HostCallError> {
+        let results = self
+            .batch
+            .execute(H::ed_on_bls12_381_bandersnatch_batch, dispatch::<H>)?;
+        self.ops
+            .iter()
+            .zip(results)
+            .map(|(op, result)| match *op {
+                TE_MUL | TE_MSM => decode_projective(&result).map(Output::Te),
+                _ => decode_projective(&result).map(Output::Sw),
+            })
+            .collect()
+    }
+}
+
+fn decode_projective<T>(result: &[u8]) -> Result<T, HostCallError>
+where
+    ArkScaleProjective<T>: Decode,
+{
+    let result = <ArkScaleProjective<T> as Decode>::decode(&mut &result[..])
+        .map_err(|_| HostCallError::DecodeFailed)?;
+    Ok(result.0)
+}
@@ -0,0 +1,22 @@
+use sp_ark_models::{
+    cost::{CostModel, LinearCost},
+    metering::Operation,
+};
+
+/// [`CostModel`] of the Bandersnatch host calls, seeded from the benchmark figures of
+/// the host calls performed by a Substrate runtime published in the README.
+///
+/// The MSM costs are fitted to the 10 and 1000 arguments figures.
+pub struct HostCostModel;
+
+impl CostModel for HostCostModel {
+    fn cost_of(op: Operation) -> Option<LinearCost> {
+        match op {
+            Operation::TeMsm => Some(LinearCost::new(583_670, 16_107)),
+            Operation::SwMsm => Some(LinearCost::new(399_411, 15_855)),
+            Operation::TeMulProjective => Some(LinearCost::new(34_630, 0)),
+            Operation::SwMulProjective => Some(LinearCost::new(40_700, 0)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig;
+use ark_ff::PrimeField;
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_std::{marker::PhantomData, vec::Vec};
+use codec::{Decode, Encode};
+use sp_ark_models::{batch, software, CurveConfig, Group};
+
+use super::ArkScale;
+use crate::{EdwardsProjective, Fr, HostFunctions, SWProjective};
+
+/// [`HostFunctions`] adapter computing each operation with the upstream arkworks
+/// implementation whenever the wrapped host call fails.
+///
+/// With the `software` feature enabled the wrapped host calls are never made.
+pub struct Fallback<H: HostFunctions>(PhantomData<fn() -> H>);
+
+impl<H: HostFunctions> HostFunctions for Fallback<H> {
+    fn ed_on_bls12_381_bandersnatch_te_msm(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| {
+                H::ed_on_bls12_381_bandersnatch_te_msm(bases.to_vec(), scalars.to_vec())
+            },
+            |(bases, scalars)| software::msm_te::<BandersnatchConfig>(bases, scalars),
+        )
+    }
+
+    fn ed_on_bls12_381_bandersnatch_sw_msm(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| {
+                H::ed_on_bls12_381_bandersnatch_sw_msm(bases.to_vec(), scalars.to_vec())
+            },
+            |(bases, scalars)| software::msm_sw::<BandersnatchConfig>(bases, scalars),
+        )
+    }
+
+    fn ed_on_bls12_381_bandersnatch_sw_mul_projective(
+        base: Vec<u8>,
+        scalar: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| {
+                H::ed_on_bls12_381_bandersnatch_sw_mul_projective(base.to_vec(), scalar.to_vec())
+            },
+            |(base, scalar)| glv_mul_projective_sw::<H>(base, scalar),
+        )
+    }
+
+    fn ed_on_bls12_381_bandersnatch_te_mul_projective(
+        base: Vec<u8>,
+        scalar: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (base.as_slice(), scalar.as_slice()),
+            |(base, scalar)| {
+                H::ed_on_bls12_381_bandersnatch_te_mul_projective(base.to_vec(), scalar.to_vec())
+            },
+            |(base, scalar)| glv_mul_projective_te::<H>(base, scalar),
+        )
+    }
+
+    fn ed_on_bls12_381_bandersnatch_te_register_bases(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        // Software keeps no registry, leaving the bases to plain MSMs.
+        if cfg!(feature = "software") {
+            return Err(());
+        }
+        H::ed_on_bls12_381_bandersnatch_te_register_bases(bases)
+    }
+
+    fn ed_on_bls12_381_bandersnatch_te_msm_with_handle(
+        handle: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        if cfg!(feature = "software") {
+            return Err(());
+        }
+        H::ed_on_bls12_381_bandersnatch_te_msm_with_handle(handle, scalars)
+    }
+
+    fn ed_on_bls12_381_bandersnatch_te_msm_small(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| {
+                H::ed_on_bls12_381_bandersnatch_te_msm_small(bases.to_vec(), scalars.to_vec())
+            },
+            |(bases, scalars)| {
+                software::msm_small_te::<ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig>(
+                    bases, scalars,
+                )
+            },
+        )
+    }
+
+    fn ed_on_bls12_381_bandersnatch_sw_msm_small(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            (bases.as_slice(), scalars.as_slice()),
+            |(bases, scalars)| {
+                H::ed_on_bls12_381_bandersnatch_sw_msm_small(bases.to_vec(), scalars.to_vec())
+            },
+            |(bases, scalars)| {
+                software::msm_small_sw::<ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig>(
+                    bases, scalars,
+                )
+            },
+        )
+    }
+
+    fn ed_on_bls12_381_bandersnatch_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        with_fallback(
+            calls.as_slice(),
+            |calls| H::ed_on_bls12_381_bandersnatch_batch(calls.to_vec()),
+            |calls| batch::execute(calls, crate::batch::dispatch::<Self>),
+        )
+    }
+}
+
+/// [`software::with_fallback`], never calling the host with the `software` feature
+/// of this crate enabled.
+fn with_fallback<A: Copy, R>(
+    args: A,
+    host: impl FnOnce(A) -> Result<R, ()>,
+    fallback: impl FnOnce(A) -> Result<R, ()>,
+) -> Result<R, ()> {
+    software::with_fallback(cfg!(feature = "software"), args, host, fallback)
+}
+
+/// [`software::mul_projective_sw`] with [`crate::BandersnatchConfig::glv_mul_sw`].
+fn glv_mul_projective_sw<H: HostFunctions>(base: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ()> {
+    let base = ArkScaleProjective::<SWProjective<H>>::decode(&mut &base[..]).map_err(|_| ())?;
+    let (low, high) = split_scalar(scalar)?;
+
+    let result = crate::BandersnatchConfig::<H>::glv_mul_sw(&base.0.double().double(), &high);
+    let result = (0..low).fold(result, |result, _| result + base.0);
+
+    let result: ArkScaleProjective<SWProjective<H>> = result.into();
+    Ok(result.encode())
+}
+
+/// [`software::mul_projective_te`] with [`crate::BandersnatchConfig::glv_mul_te`].
+fn glv_mul_projective_te<H: HostFunctions>(base: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ()> {
+    let base =
+        ArkScaleProjective::<EdwardsProjective<H>>::decode(&mut &base[..]).map_err(|_| ())?;
+    let (low, high) = split_scalar(scalar)?;
+
+    let result = crate::BandersnatchConfig::<H>::glv_mul_te(&base.0.double().double(), &high);
+    let result = (0..low).fold(result, |result, _| result + base.0);
+
+    let result: ArkScaleProjective<EdwardsProjective<H>> = result.into();
+    Ok(result.encode())
+}
+
+/// Split the encoded scalar `k` into `k mod 4` and `⌊k / 4⌋ mod r`, as
+/// `k·P = (k mod 4)·P + ⌊k / 4⌋·(4·P)` and `4·P`, cleared of the cofactor, is in the
+/// prime order subgroup the GLV method requires, whatever the point `P`.
+fn split_scalar(scalar: &[u8]) -> Result<(u64, Fr), ()> {
+    let scalar = ArkScale::<Vec<u64>>::decode(&mut &scalar[..]).map_err(|_| ())?;
+    let low = scalar.0.first().map_or(0, |limb| limb & 3);
+    let bytes = scalar
+        .0
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect::<Vec<_>>();
+    let high = (Fr::from_le_bytes_mod_order(&bytes) - Fr::from(low))
+        * <BandersnatchConfig as CurveConfig>::COFACTOR_INV;
+    Ok((low, high))
+}
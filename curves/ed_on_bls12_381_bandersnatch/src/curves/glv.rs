@@ -0,0 +1,119 @@
+use ark_ff::{Field, MontFp};
+use sp_ark_models::{
+    glv::{self, GlvConfig},
+    short_weierstrass,
+    twisted_edwards::{Affine, MontCurveConfig, Projective},
+    AffineRepr, CurveGroup,
+};
+
+use crate::{BandersnatchConfig, Fq, Fr, HostFunctions};
+
+/// X0 = 42460977304182762931716743824405123254375045638571669698531889431804823178961,
+/// the abscissa of the 2-torsion point in the kernel of the endomorphism
+const X0: Fq =
+    MontFp!("42460977304182762931716743824405123254375045638571669698531889431804823178961");
+
+/// T = 3 * X0^2 + A, with A the short Weierstrass COEFF_A
+const T: Fq =
+    MontFp!("35484827650731063748396669747216844996598387089274032563585525486049249153249");
+
+/// U2 = -1/2, the abscissa scaling of the isomorphism back to the curve
+const U2: Fq =
+    MontFp!("26217937587563095239723870254092982918845276250263818911301829349969290592256");
+
+/// U3, the ordinate scaling of the isomorphism back to the curve
+const U3: Fq =
+    MontFp!("8089994378958280414021351509578931277711957629993758335181991576135134307306");
+
+/// Endomorphism of the short Weierstrass form, acting as the multiplication by
+/// [`GlvConfig::LAMBDA`] on the prime order subgroup.
+///
+/// It is the 2-isogeny with kernel `(X0, 0)` given by Vélu's formulas
+/// `(x, y) -> (x + T / (x - X0), y * (1 - T / (x - X0)^2))`, followed by the
+/// isomorphism `(x, y) -> (U2 * x, U3 * y)` back to the curve.
+pub fn endomorphism<H: HostFunctions>(
+    p: &short_weierstrass::Affine<BandersnatchConfig<H>>,
+) -> short_weierstrass::Affine<BandersnatchConfig<H>> {
+    let inverse = match (p.x - X0).inverse() {
+        Some(inverse) if !p.infinity => inverse,
+        // The identity and the 2-torsion point in the kernel.
+        _ => return short_weierstrass::Affine::identity(),
+    };
+    let t = T * inverse;
+    short_weierstrass::Affine::new_unchecked(U2 * (p.x + t), U3 * p.y * (Fq::ONE - t * inverse))
+}
+
+impl<H: HostFunctions> GlvConfig for BandersnatchConfig<H> {
+    /// LAMBDA = 8913659658109529928382530854484400854125314752504019737736543920008458395397,
+    /// a square root of -2
+    const LAMBDA: Fr =
+        MontFp!("8913659658109529928382530854484400854125314752504019737736543920008458395397");
+
+    /// BASIS = (113482231691339203864511368254957623327, 10741319382058138887739339959866629956),
+    ///         (21482638764116277775478679919733259912, -113482231691339203864511368254957623327)
+    const BASIS: [(bool, u128); 4] = [
+        (false, 113482231691339203864511368254957623327),
+        (false, 10741319382058138887739339959866629956),
+        (false, 21482638764116277775478679919733259912),
+        (true, 113482231691339203864511368254957623327),
+    ];
+}
+
+impl<H: HostFunctions> BandersnatchConfig<H> {
+    /// Multiply `base`, which must be in the prime order subgroup, by `scalar` in
+    /// software with the GLV method, using [`endomorphism`].
+    pub fn glv_mul_sw(
+        base: &short_weierstrass::Projective<Self>,
+        scalar: &Fr,
+    ) -> short_weierstrass::Projective<Self> {
+        let endo = endomorphism(&base.into_affine()).into_group();
+        glv::mul(base, &endo, scalar)
+    }
+
+    /// Multiply `base`, which must be in the prime order subgroup, by `scalar` in
+    /// software with the GLV method, using [`endomorphism`] through the birational
+    /// equivalence with the short Weierstrass form.
+    pub fn glv_mul_te(base: &Projective<Self>, scalar: &Fr) -> Projective<Self> {
+        let endo = te_from_sw(&endomorphism(&sw_from_te(&base.into_affine())));
+        glv::mul(base, &endo.into_group(), scalar)
+    }
+}
+
+/// Map a twisted Edwards point to the short Weierstrass form, through the
+/// Montgomery form `(u, v) = ((1 + y) / (1 - y), u / x)`.
+fn sw_from_te<H: HostFunctions>(
+    p: &Affine<BandersnatchConfig<H>>,
+) -> short_weierstrass::Affine<BandersnatchConfig<H>> {
+    let (a, b) = montgomery_coeffs::<H>();
+    let inverse = match ((Fq::ONE - p.y) * p.x).inverse() {
+        Some(inverse) => inverse,
+        // The identity and the point of order 2.
+        None => return short_weierstrass::Affine::identity(),
+    };
+    let v = (Fq::ONE + p.y) * inverse;
+    let u = v * p.x;
+    let b_inverse = b.inverse().expect("B is not zero");
+    short_weierstrass::Affine::new_unchecked((u + a / Fq::from(3u8)) * b_inverse, v * b_inverse)
+}
+
+/// Inverse of [`sw_from_te`].
+fn te_from_sw<H: HostFunctions>(
+    p: &short_weierstrass::Affine<BandersnatchConfig<H>>,
+) -> Affine<BandersnatchConfig<H>> {
+    let (a, b) = montgomery_coeffs::<H>();
+    let u = b * p.x - a / Fq::from(3u8);
+    let v = b * p.y;
+    match (v * (u + Fq::ONE)).inverse() {
+        Some(inverse) if !p.infinity => {
+            Affine::new_unchecked(u * (u + Fq::ONE) * inverse, (u - Fq::ONE) * v * inverse)
+        }
+        _ => Affine::zero(),
+    }
+}
+
+fn montgomery_coeffs<H: HostFunctions>() -> (Fq, Fq) {
+    (
+        <BandersnatchConfig<H> as MontCurveConfig>::COEFF_A,
+        <BandersnatchConfig<H> as MontCurveConfig>::COEFF_B,
+    )
+}
@@ -5,17 +5,28 @@ use ark_std::vec::Vec;
 use codec::{Decode, Encode};
 use sp_ark_models::{
     models::CurveConfig,
+    msm::{SWTryMsm, TETryMsm},
+    registry::{BasesRegistry, Handle},
     short_weierstrass::{self, SWCurveConfig},
+    small_msm::SmallMsm,
+    software,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
+    HostCallError, VariableBaseMSM,
 };
 
 use crate::{Fq, Fr};
 
 const HOST_CALL: ark_scale::Usage = ark_scale::HOST_CALL;
-type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
+pub(crate) type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
 
+mod cost;
+mod fallback;
+mod glv;
+mod metered;
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
+
+pub use self::{cost::HostCostModel, fallback::Fallback, glv::endomorphism, metered::Metered};
 
 pub type EdwardsAffine<H> = Affine<BandersnatchConfig<H>>;
 pub type EdwardsProjective<H> = Projective<BandersnatchConfig<H>>;
@@ -23,6 +34,12 @@ pub type EdwardsProjective<H> = Projective<BandersnatchConfig<H>>;
 pub type SWAffine<H> = short_weierstrass::Affine<BandersnatchConfig<H>>;
 pub type SWProjective<H> = short_weierstrass::Projective<BandersnatchConfig<H>>;
 
+/// Precomputed multiples of a fixed point of the twisted Edwards form, such as the
+/// generator, see [`sp_ark_models::fixed_base`].
+pub type FixedBase<H> = sp_ark_models::fixed_base::FixedBase<EdwardsProjective<H>>;
+/// Precomputed multiples of a fixed point of the short Weierstrass form.
+pub type SWFixedBase<H> = sp_ark_models::fixed_base::FixedBase<SWProjective<H>>;
+
 /// `bandersnatch` is an incomplete twisted Edwards curve. These curves have
 /// equations of the form: ax² + y² = 1 + dx²y².
 /// over some base finite field Fq.
@@ -74,6 +91,45 @@ pub trait HostFunctions: 'static {
         base: Vec<u8>,
         scalar: Vec<u8>,
     ) -> Result<Vec<u8>, ()>;
+
+    /// Provided as unavailable for hosts without a registry of bases, in which case
+    /// [`RegisteredBases`](crate::registry::RegisteredBases) computes plain MSMs.
+    fn ed_on_bls12_381_bandersnatch_te_register_bases(_bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without a registry of bases, in which case
+    /// [`RegisteredBases`](crate::registry::RegisteredBases) computes plain MSMs.
+    fn ed_on_bls12_381_bandersnatch_te_msm_with_handle(
+        _handle: Vec<u8>,
+        _scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without small scalar MSMs, in which case
+    /// [`SmallMsm`](sp_ark_models::small_msm::SmallMsm) computes plain MSMs.
+    fn ed_on_bls12_381_bandersnatch_te_msm_small(
+        _bases: Vec<u8>,
+        _scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without small scalar MSMs, in which case
+    /// [`SmallMsm`](sp_ark_models::small_msm::SmallMsm) computes plain MSMs.
+    fn ed_on_bls12_381_bandersnatch_sw_msm_small(
+        _bases: Vec<u8>,
+        _scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+
+    /// Provided as unavailable for hosts without batches, in which case
+    /// [`HostBatch`](crate::batch::HostBatch) makes one host call per operation.
+    fn ed_on_bls12_381_bandersnatch_batch(_calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
 }
 
 impl<H: HostFunctions> CurveConfig for BandersnatchConfig<H> {
@@ -89,6 +145,97 @@ impl<H: HostFunctions> CurveConfig for BandersnatchConfig<H> {
         MontFp!("9831726595336160714896451345284868594481866920080427688839802480047265754601");
 }
 
+impl<H: HostFunctions> BandersnatchConfig<H> {
+    /// Fallible version of [`TECurveConfig::msm`], surfacing host call errors.
+    pub fn try_te_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
+
+        let result = H::ed_on_bls12_381_bandersnatch_te_msm(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`TECurveConfig::mul_projective`], surfacing host call errors.
+    pub fn try_te_mul(
+        base: &Projective<Self>,
+        scalar: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result =
+            H::ed_on_bls12_381_bandersnatch_te_mul_projective(base.encode(), scalar.encode())
+                .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> BasesRegistry<Projective<Self>> for BandersnatchConfig<H> {
+    fn register_bases(bases: &[Affine<Self>]) -> Result<Handle, HostCallError> {
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+
+        let result = H::ed_on_bls12_381_bandersnatch_te_register_bases(bases.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        Handle::try_from(result.as_slice()).map_err(|_| HostCallError::DecodeFailed)
+    }
+
+    fn msm_with_handle(handle: &Handle, scalars: &[Fr]) -> Result<Projective<Self>, HostCallError> {
+        let scalars: ArkScale<&[Fr]> = scalars.into();
+
+        let result =
+            H::ed_on_bls12_381_bandersnatch_te_msm_with_handle(handle.to_vec(), scalars.encode())
+                .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> TETryMsm for BandersnatchConfig<H> {
+    fn try_msm(
+        bases: &[Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<Projective<Self>, HostCallError> {
+        Self::try_te_msm(bases, scalars)
+    }
+}
+
+impl<H: HostFunctions> SmallMsm<Affine<Self>> for BandersnatchConfig<H> {
+    fn try_msm_small(
+        bases: &[Affine<Self>],
+        limbs: &[u64],
+    ) -> Result<Projective<Self>, HostCallError> {
+        let bases: ArkScale<&[Affine<Self>]> = bases.into();
+        let limbs: ArkScale<&[u64]> = limbs.into();
+
+        let result = H::ed_on_bls12_381_bandersnatch_te_msm_small(bases.encode(), limbs.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result =
+            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice())
+                .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
 impl<H: HostFunctions> TECurveConfig for BandersnatchConfig<H> {
     /// COEFF_A = -5
     const COEFF_A: Fq = MontFp!("-5");
@@ -113,43 +260,23 @@ impl<H: HostFunctions> TECurveConfig for BandersnatchConfig<H> {
         bases: &[Affine<Self>],
         scalars: &[<Self as CurveConfig>::ScalarField],
     ) -> Result<Projective<Self>, usize> {
-        let bases: ArkScale<&[Affine<Self>]> = bases.into();
-        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
-
-        let result =
-            H::ed_on_bls12_381_bandersnatch_te_msm(bases.encode(), scalars.encode()).unwrap();
-
-        let result = <ArkScaleProjective<Projective<BandersnatchConfig<H>>> as Decode>::decode(
-            &mut result.as_slice(),
-        );
-        result.map_err(|_| 0).map(|res| res.0)
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        // Host call errors fall back to software, `TETryMsm::try_msm` surfaces them.
+        Ok(Self::try_te_msm(bases, scalars)
+            .unwrap_or_else(|_| VariableBaseMSM::msm_unchecked(bases, scalars)))
     }
 
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: ArkScaleProjective<Projective<Self>> = (*base).into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result =
-            H::ed_on_bls12_381_bandersnatch_te_mul_projective(base.encode(), scalar.encode())
-                .unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_te_mul` surfaces them.
+        Self::try_te_mul(base, scalar).unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 
     fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
-        let base: Projective<Self> = (*base).into();
-        let base: ArkScaleProjective<Projective<Self>> = base.into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result =
-            H::ed_on_bls12_381_bandersnatch_te_mul_projective(base.encode(), scalar.encode())
-                .unwrap();
-
-        let result =
-            <ArkScaleProjective<Projective<Self>> as Decode>::decode(&mut result.as_slice());
-        result.unwrap().0
+        // Host call errors fall back to software, `try_te_mul` surfaces them.
+        Self::try_te_mul(&(*base).into(), scalar)
+            .unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 }
 
@@ -190,6 +317,76 @@ const SW_GENERATOR_X: Fq =
 const SW_GENERATOR_Y: Fq =
     MontFp!("12663882780877899054958035777720958383845500985908634476792678820121468453298");
 
+impl<H: HostFunctions> BandersnatchConfig<H> {
+    /// Fallible version of [`SWCurveConfig::msm`], surfacing host call errors.
+    pub fn try_sw_msm(
+        bases: &[short_weierstrass::Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<short_weierstrass::Projective<Self>, HostCallError> {
+        if bases.len() != scalars.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        let bases: ArkScale<&[short_weierstrass::Affine<Self>]> = bases.into();
+        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
+
+        let result = H::ed_on_bls12_381_bandersnatch_sw_msm(bases.encode(), scalars.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScaleProjective<short_weierstrass::Projective<Self>> as Decode>::decode(
+            &mut result.as_slice(),
+        )
+        .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+
+    /// Fallible version of [`SWCurveConfig::mul_projective`], surfacing host call errors.
+    pub fn try_sw_mul(
+        base: &short_weierstrass::Projective<Self>,
+        scalar: &[u64],
+    ) -> Result<short_weierstrass::Projective<Self>, HostCallError> {
+        let base: ArkScaleProjective<short_weierstrass::Projective<Self>> = (*base).into();
+        let scalar: ArkScale<&[u64]> = scalar.into();
+
+        let result =
+            H::ed_on_bls12_381_bandersnatch_sw_mul_projective(base.encode(), scalar.encode())
+                .map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScaleProjective<short_weierstrass::Projective<Self>> as Decode>::decode(
+            &mut result.as_slice(),
+        )
+        .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
+impl<H: HostFunctions> SWTryMsm for BandersnatchConfig<H> {
+    fn try_msm(
+        bases: &[short_weierstrass::Affine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<short_weierstrass::Projective<Self>, HostCallError> {
+        Self::try_sw_msm(bases, scalars)
+    }
+}
+
+impl<H: HostFunctions> SmallMsm<short_weierstrass::Affine<Self>> for BandersnatchConfig<H> {
+    fn try_msm_small(
+        bases: &[short_weierstrass::Affine<Self>],
+        limbs: &[u64],
+    ) -> Result<short_weierstrass::Projective<Self>, HostCallError> {
+        let bases: ArkScale<&[short_weierstrass::Affine<Self>]> = bases.into();
+        let limbs: ArkScale<&[u64]> = limbs.into();
+
+        let result = H::ed_on_bls12_381_bandersnatch_sw_msm_small(bases.encode(), limbs.encode())
+            .map_err(|_| HostCallError::HostRejected)?;
+
+        let result = <ArkScaleProjective<short_weierstrass::Projective<Self>> as Decode>::decode(
+            &mut result.as_slice(),
+        )
+        .map_err(|_| HostCallError::DecodeFailed)?;
+        Ok(result.0)
+    }
+}
+
 impl<H: HostFunctions> SWCurveConfig for BandersnatchConfig<H> {
     /// COEFF_A = 10773120815616481058602537765553212789256758185246796157495669123169359657269
     const COEFF_A: Self::BaseField =
@@ -204,56 +401,31 @@ impl<H: HostFunctions> SWCurveConfig for BandersnatchConfig<H> {
         ark_ec::short_weierstrass::Affine::<Self>::new_unchecked(SW_GENERATOR_X, SW_GENERATOR_Y);
 
     fn msm(
-        bases: &[ark_ec::short_weierstrass::Affine<Self>],
+        bases: &[short_weierstrass::Affine<Self>],
         scalars: &[<Self as CurveConfig>::ScalarField],
-    ) -> Result<ark_ec::short_weierstrass::Projective<Self>, usize> {
-        let bases: ArkScale<&[ark_ec::short_weierstrass::Affine<Self>]> = bases.into();
-        let scalars: ArkScale<&[<Self as CurveConfig>::ScalarField]> = scalars.into();
-
-        let result =
-            H::ed_on_bls12_381_bandersnatch_sw_msm(bases.encode(), scalars.encode()).unwrap();
-
-        let result =
-            <ArkScaleProjective<ark_ec::short_weierstrass::Projective<Self>> as Decode>::decode(
-                &mut result.as_slice(),
-            );
-        result.map_err(|_| 0).map(|res| res.0)
+    ) -> Result<short_weierstrass::Projective<Self>, usize> {
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        // Host call errors fall back to software, `SWTryMsm::try_msm` surfaces them.
+        Ok(Self::try_sw_msm(bases, scalars)
+            .unwrap_or_else(|_| VariableBaseMSM::msm_unchecked(bases, scalars)))
     }
 
     fn mul_projective(
-        base: &ark_ec::short_weierstrass::Projective<Self>,
+        base: &short_weierstrass::Projective<Self>,
         scalar: &[u64],
-    ) -> ark_ec::short_weierstrass::Projective<Self> {
-        let base: ArkScaleProjective<ark_ec::short_weierstrass::Projective<Self>> = (*base).into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result =
-            H::ed_on_bls12_381_bandersnatch_sw_mul_projective(base.encode(), scalar.encode())
-                .unwrap();
-
-        let result =
-            <ArkScaleProjective<ark_ec::short_weierstrass::Projective<Self>> as Decode>::decode(
-                &mut result.as_slice(),
-            );
-        result.unwrap().0
+    ) -> short_weierstrass::Projective<Self> {
+        // Host call errors fall back to software, `try_sw_mul` surfaces them.
+        Self::try_sw_mul(base, scalar).unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 
     fn mul_affine(
-        base: &ark_ec::short_weierstrass::Affine<Self>,
+        base: &short_weierstrass::Affine<Self>,
         scalar: &[u64],
-    ) -> ark_ec::short_weierstrass::Projective<Self> {
-        let base: ark_ec::short_weierstrass::Projective<Self> = (*base).into();
-        let base: ArkScaleProjective<ark_ec::short_weierstrass::Projective<Self>> = base.into();
-        let scalar: ArkScale<&[u64]> = scalar.into();
-
-        let result =
-            H::ed_on_bls12_381_bandersnatch_sw_mul_projective(base.encode(), scalar.encode())
-                .unwrap();
-
-        let result =
-            <ArkScaleProjective<ark_ec::short_weierstrass::Projective<Self>> as Decode>::decode(
-                &mut result.as_slice(),
-            );
-        result.unwrap().0
+    ) -> short_weierstrass::Projective<Self> {
+        // Host call errors fall back to software, `try_sw_mul` surfaces them.
+        Self::try_sw_mul(&(*base).into(), scalar)
+            .unwrap_or_else(|_| software::double_and_add(base, scalar))
     }
 }
@@ -1,4 +1,4 @@
-use crate::{EdwardsProjective as EdwardsProjectiveHost, HostFunctions};
+use crate::{EdwardsProjective as EdwardsProjectiveHost, Fallback, HostFunctions};
 use ark_algebra_test_templates::*;
 pub struct Host {}
 
@@ -36,3 +36,43 @@ impl HostFunctions for Host {
 type EdwardsProjective = EdwardsProjectiveHost<Host>;
 
 test_group!(te; EdwardsProjective; te);
+
+struct Unavailable;
+
+impl HostFunctions for Unavailable {
+    fn ed_on_bls12_381_bandersnatch_te_msm(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn ed_on_bls12_381_bandersnatch_sw_msm(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn ed_on_bls12_381_bandersnatch_te_mul_projective(
+        _: Vec<u8>,
+        _: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn ed_on_bls12_381_bandersnatch_sw_mul_projective(
+        _: Vec<u8>,
+        _: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+type EdwardsProjectiveFallback = EdwardsProjectiveHost<Fallback<Unavailable>>;
+
+test_group!(te_fallback; EdwardsProjectiveFallback; te);
+
+#[test]
+fn test_fallback_matches_host() {
+    use ark_std::UniformRand;
+    use sp_ark_models::{AffineRepr, CurveGroup, Group};
+
+    let mut rng = ark_std::test_rng();
+    let s = crate::Fr::rand(&mut rng);
+
+    let p = (EdwardsProjective::generator() * s).into_affine();
+    let p_fallback = (EdwardsProjectiveFallback::generator() * s).into_affine();
+    assert_eq!(p.xy(), p_fallback.xy());
+}
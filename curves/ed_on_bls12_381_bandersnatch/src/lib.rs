@@ -31,7 +31,12 @@
 
 #[cfg(feature = "r1cs")]
 pub use ark_ed_on_bls12_381_bandersnatch::constraints;
+pub mod batch;
 pub mod curves;
+pub mod pedersen;
+pub mod registry;
+pub mod schnorr;
+pub mod vrf;
 
 pub use ark_ed_on_bls12_381_bandersnatch::{fq, fq::*, fr, fr::*};
 pub use curves::*;
@@ -0,0 +1,259 @@
+//! ECVRF over the Bandersnatch twisted Edwards form, following
+//! [RFC 9381](https://www.rfc-editor.org/rfc/rfc9381) with the try and increment
+//! encoding to the curve and SHA-512.
+//!
+//! Points are encoded compressed and scalars little endian, as by `ark-serialize`.
+//! The scalar multiplications go through the host functions.
+
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::fmt;
+use sha2::{Digest, Sha512};
+use sp_ark_models::{AffineRepr, CurveGroup, Group, HostCallError};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{BandersnatchConfig, EdwardsAffine, EdwardsProjective, Fr, HostFunctions};
+
+#[cfg(test)]
+mod tests;
+
+/// Suite string, used as domain separation tag.
+pub const SUITE_STRING: &[u8] = b"Bandersnatch_SHA-512_TAI";
+
+/// Size of an encoded point.
+pub const POINT_LEN: usize = 32;
+/// Size of an encoded challenge.
+pub const CHALLENGE_LEN: usize = 32;
+/// Size of an encoded scalar.
+pub const SCALAR_LEN: usize = 32;
+/// Size of an encoded proof.
+pub const PROOF_LEN: usize = POINT_LEN + CHALLENGE_LEN + SCALAR_LEN;
+/// Size of the VRF output hash.
+pub const OUTPUT_LEN: usize = 64;
+
+/// VRF secret key, zeroized when dropped.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct SecretKey(Fr);
+
+impl SecretKey {
+    /// Derive a secret key from `seed`.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        SecretKey(Fr::from_le_bytes_mod_order(&Sha512::digest(seed)))
+    }
+
+    /// Secret key with the scalar `scalar`, `None` if it is zero.
+    pub fn from_scalar(scalar: Fr) -> Option<Self> {
+        (!scalar.is_zero()).then_some(SecretKey(scalar))
+    }
+
+    /// Public key of the secret key.
+    pub fn public<H: HostFunctions>(&self) -> Result<PublicKey<H>, HostCallError> {
+        Ok(PublicKey(
+            mul(&EdwardsAffine::<H>::generator(), self.0)?.into_affine(),
+        ))
+    }
+}
+
+/// VRF public key.
+pub struct PublicKey<H: HostFunctions>(pub EdwardsAffine<H>);
+
+/// VRF proof, also carrying the VRF output point `Γ`.
+pub struct Proof<H: HostFunctions> {
+    pub gamma: EdwardsAffine<H>,
+    pub c: Fr,
+    pub s: Fr,
+}
+
+// Implemented by hand as the derives would require `H` itself to implement the traits.
+impl<H: HostFunctions> Clone for PublicKey<H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H: HostFunctions> Copy for PublicKey<H> {}
+
+impl<H: HostFunctions> PartialEq for PublicKey<H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<H: HostFunctions> Eq for PublicKey<H> {}
+
+impl<H: HostFunctions> fmt::Debug for PublicKey<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PublicKey").field(&self.0).finish()
+    }
+}
+
+impl<H: HostFunctions> Clone for Proof<H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H: HostFunctions> Copy for Proof<H> {}
+
+impl<H: HostFunctions> PartialEq for Proof<H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.gamma == other.gamma && self.c == other.c && self.s == other.s
+    }
+}
+
+impl<H: HostFunctions> Eq for Proof<H> {}
+
+impl<H: HostFunctions> fmt::Debug for Proof<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proof")
+            .field("gamma", &self.gamma)
+            .field("c", &self.c)
+            .field("s", &self.s)
+            .finish()
+    }
+}
+
+impl<H: HostFunctions> Proof<H> {
+    /// Encoding of the proof, `Γ || c || s`.
+    pub fn to_bytes(&self) -> [u8; PROOF_LEN] {
+        let mut bytes = [0; PROOF_LEN];
+        bytes[..POINT_LEN].copy_from_slice(&encode_point(&self.gamma));
+        bytes[POINT_LEN..POINT_LEN + CHALLENGE_LEN].copy_from_slice(&encode_scalar(&self.c));
+        bytes[POINT_LEN + CHALLENGE_LEN..].copy_from_slice(&encode_scalar(&self.s));
+        bytes
+    }
+
+    /// Decode a proof, `None` if `Γ` is not on the curve or a scalar is not reduced.
+    pub fn from_bytes(bytes: &[u8; PROOF_LEN]) -> Option<Self> {
+        let gamma =
+            EdwardsAffine::<H>::deserialize_compressed_unchecked(&bytes[..POINT_LEN]).ok()?;
+        let c = decode_scalar(&bytes[POINT_LEN..POINT_LEN + CHALLENGE_LEN])?;
+        let s = decode_scalar(&bytes[POINT_LEN + CHALLENGE_LEN..])?;
+        Some(Proof { gamma, c, s })
+    }
+}
+
+/// Prove the VRF evaluation of `alpha` by `sk`.
+pub fn prove<H: HostFunctions>(sk: &SecretKey, alpha: &[u8]) -> Result<Proof<H>, HostCallError> {
+    let pk = sk.public::<H>()?;
+    let h = encode_to_curve::<H>(&pk, alpha);
+    let gamma = mul(&h, sk.0)?.into_affine();
+    let k = nonce(sk, &h);
+    let c = challenge(&[
+        pk.0,
+        h,
+        gamma,
+        mul(&EdwardsAffine::<H>::generator(), k)?.into_affine(),
+        mul(&h, k)?.into_affine(),
+    ]);
+    Ok(Proof {
+        gamma,
+        c,
+        s: k + c * sk.0,
+    })
+}
+
+/// Verify the proof `proof` of the VRF evaluation of `alpha` by `pk`, returning the
+/// VRF output hash if it is valid.
+pub fn verify<H: HostFunctions>(
+    pk: &PublicKey<H>,
+    alpha: &[u8],
+    proof: &Proof<H>,
+) -> Option<[u8; OUTPUT_LEN]> {
+    if !pk.0.is_on_curve() || !pk.0.is_in_correct_subgroup_assuming_on_curve() || pk.0.is_zero() {
+        return None;
+    }
+    let h = encode_to_curve::<H>(pk, alpha);
+    // U = s·B - c·Y, V = s·H - c·Γ
+    let u = BandersnatchConfig::<H>::try_te_msm(
+        &[EdwardsAffine::<H>::generator(), pk.0],
+        &[proof.s, -proof.c],
+    )
+    .ok()?;
+    let v = BandersnatchConfig::<H>::try_te_msm(&[h, proof.gamma], &[proof.s, -proof.c]).ok()?;
+    let c = challenge(&[pk.0, h, proof.gamma, u.into_affine(), v.into_affine()]);
+    (c == proof.c).then(|| proof_to_hash(proof))
+}
+
+/// VRF output hash of `proof`, to be used only once the proof has been verified.
+pub fn proof_to_hash<H: HostFunctions>(proof: &Proof<H>) -> [u8; OUTPUT_LEN] {
+    let gamma = proof.gamma.into_group().double().double().into_affine();
+    Sha512::new()
+        .chain_update(SUITE_STRING)
+        .chain_update([0x03])
+        .chain_update(encode_point(&gamma))
+        .chain_update([0x00])
+        .finalize()
+        .into()
+}
+
+fn mul<H: HostFunctions>(
+    base: &EdwardsAffine<H>,
+    scalar: Fr,
+) -> Result<EdwardsProjective<H>, HostCallError> {
+    BandersnatchConfig::<H>::try_te_mul(&(*base).into(), scalar.into_bigint().as_ref())
+}
+
+fn encode_point<H: HostFunctions>(point: &EdwardsAffine<H>) -> [u8; POINT_LEN] {
+    let mut bytes = [0; POINT_LEN];
+    point
+        .serialize_compressed(&mut bytes[..])
+        .expect("compressed points fit in POINT_LEN bytes");
+    bytes
+}
+
+fn encode_scalar(scalar: &Fr) -> [u8; SCALAR_LEN] {
+    let mut bytes = [0; SCALAR_LEN];
+    bytes.copy_from_slice(&scalar.into_bigint().to_bytes_le());
+    bytes
+}
+
+fn decode_scalar(bytes: &[u8]) -> Option<Fr> {
+    Fr::deserialize_compressed(bytes).ok()
+}
+
+/// `ECVRF_encode_to_curve_try_and_increment`, salted with the public key.
+fn encode_to_curve<H: HostFunctions>(pk: &PublicKey<H>, alpha: &[u8]) -> EdwardsAffine<H> {
+    let salt = encode_point(&pk.0);
+    for ctr in 0..=u8::MAX {
+        let hash = Sha512::new()
+            .chain_update(SUITE_STRING)
+            .chain_update([0x01])
+            .chain_update(salt)
+            .chain_update(alpha)
+            .chain_update([ctr])
+            .chain_update([0x00])
+            .finalize();
+        if let Ok(point) = EdwardsAffine::<H>::deserialize_compressed_unchecked(&hash[..POINT_LEN])
+        {
+            let point = point.into_group().double().double();
+            if !point.is_zero() {
+                return point.into_affine();
+            }
+        }
+    }
+    // Every candidate failing has probability 2⁻²⁵⁶.
+    unreachable!("no valid point in 256 tries")
+}
+
+/// `ECVRF_nonce_generation_RFC8032`.
+fn nonce<H: HostFunctions>(sk: &SecretKey, h: &EdwardsAffine<H>) -> Fr {
+    let hashed_sk = Sha512::digest(encode_scalar(&sk.0));
+    let hash = Sha512::new()
+        .chain_update(&hashed_sk[32..])
+        .chain_update(encode_point(h))
+        .finalize();
+    Fr::from_le_bytes_mod_order(&hash)
+}
+
+/// `ECVRF_challenge_generation`.
+fn challenge<H: HostFunctions>(points: &[EdwardsAffine<H>]) -> Fr {
+    let mut hasher = Sha512::new();
+    hasher.update(SUITE_STRING);
+    hasher.update([0x02]);
+    for point in points {
+        hasher.update(encode_point(point));
+    }
+    hasher.update([0x00]);
+    Fr::from_le_bytes_mod_order(&hasher.finalize()[..CHALLENGE_LEN])
+}
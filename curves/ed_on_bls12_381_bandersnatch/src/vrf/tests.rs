@@ -0,0 +1,60 @@
+use super::*;
+use crate::curves::tests::Host;
+
+fn secret_key() -> SecretKey {
+    SecretKey::from_seed(b"bandersnatch vrf test seed")
+}
+
+#[test]
+fn prove_and_verify() {
+    let sk = secret_key();
+    let pk = sk.public::<Host>().unwrap();
+    let proof = prove::<Host>(&sk, b"sample").unwrap();
+
+    assert_eq!(verify(&pk, b"sample", &proof), Some(proof_to_hash(&proof)));
+    assert_eq!(verify(&pk, b"other", &proof), None);
+
+    let other_pk = SecretKey::from_seed(b"other seed")
+        .public::<Host>()
+        .unwrap();
+    assert_eq!(verify(&other_pk, b"sample", &proof), None);
+
+    let mut wrong_proof = proof;
+    wrong_proof.s += Fr::from(1u8);
+    assert_eq!(verify(&pk, b"sample", &wrong_proof), None);
+}
+
+#[test]
+fn output_is_deterministic() {
+    let sk = secret_key();
+    let proof = prove::<Host>(&sk, b"sample").unwrap();
+    assert_eq!(prove::<Host>(&sk, b"sample").unwrap(), proof);
+    assert_ne!(
+        proof_to_hash(&prove::<Host>(&sk, b"other").unwrap()),
+        proof_to_hash(&proof)
+    );
+}
+
+#[test]
+fn proof_encoding() {
+    let proof = prove::<Host>(&secret_key(), b"sample").unwrap();
+    let bytes = proof.to_bytes();
+    assert_eq!(Proof::<Host>::from_bytes(&bytes), Some(proof));
+
+    let mut bytes = bytes;
+    bytes[PROOF_LEN - 1] = 0xff;
+    assert_eq!(Proof::<Host>::from_bytes(&bytes), None);
+}
+
+#[test]
+fn secret_key_from_scalar() {
+    assert!(SecretKey::from_scalar(Fr::from(0u8)).is_none());
+    assert!(
+        SecretKey::from_scalar(Fr::from(1u8))
+            .unwrap()
+            .public::<Host>()
+            .unwrap()
+            .0
+            == EdwardsAffine::<Host>::generator()
+    );
+}
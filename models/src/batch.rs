@@ -0,0 +1,100 @@
+//! Batches of independent host calls.
+//!
+//! Every host call pays a fixed overhead to cross the host boundary, which
+//! dominates small operations such as single scalar multiplications. A [`Batch`]
+//! queues host calls, each an opcode of the curve crate with its two encoded
+//! arguments, and sends them all to the `*_batch` host function of the curve,
+//! which computes them with [`execute`] and returns their results in order.
+//!
+//! The curve crates provide a typed `HostBatch` builder on top of it, along with
+//! the `dispatch` function mapping the opcodes to their host functions, which also
+//! computes the calls one by one when the host does not provide or rejects batches.
+
+#![allow(clippy::result_unit_err)]
+
+use ark_std::vec::Vec;
+use codec::{Decode, Encode};
+
+use crate::HostCallError;
+
+/// Encoded host calls.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Batch {
+    calls: Vec<(u8, Vec<u8>, Vec<u8>)>,
+}
+
+impl Batch {
+    /// Empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue the host call `op` with the encoded arguments `a` and `b`.
+    pub fn push(&mut self, op: u8, a: Vec<u8>, b: Vec<u8>) {
+        self.calls.push((op, a, b));
+    }
+
+    /// Number of queued host calls.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Whether no host call is queued.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Argument of the `*_batch` host functions.
+    pub fn encode(&self) -> Vec<u8> {
+        self.calls.encode()
+    }
+
+    /// Compute the queued host calls with `batch`, the `*_batch` host function,
+    /// or one by one with `dispatch` if it fails, returning their encoded results.
+    ///
+    /// A failed batch may have computed some of its calls before failing, which
+    /// `dispatch` then computes again: the calls must be idempotent, as are the
+    /// elliptic curve host functions, which have no side effects.
+    pub fn execute(
+        &self,
+        batch: impl FnOnce(Vec<u8>) -> Result<Vec<u8>, ()>,
+        mut dispatch: impl FnMut(u8, Vec<u8>, Vec<u8>) -> Result<Vec<u8>, ()>,
+    ) -> Result<Vec<Vec<u8>>, HostCallError> {
+        let results = match batch(self.encode()) {
+            Ok(results) => results,
+            Err(()) => {
+                return self
+                    .calls
+                    .iter()
+                    .map(|(op, a, b)| dispatch(*op, a.clone(), b.clone()))
+                    .collect::<Result<_, ()>>()
+                    .map_err(|_| HostCallError::HostRejected)
+            }
+        };
+        let results = <Vec<Vec<u8>>>::decode(&mut results.as_slice())
+            .map_err(|_| HostCallError::DecodeFailed)?;
+        if results.len() != self.len() {
+            return Err(HostCallError::DecodeFailed);
+        }
+        Ok(results)
+    }
+}
+
+/// Compute the encoded host calls `calls` with `dispatch`, returning their encoded
+/// results. The whole batch fails if any call fails.
+pub fn execute(
+    mut calls: &[u8],
+    mut dispatch: impl FnMut(u8, Vec<u8>, Vec<u8>) -> Result<Vec<u8>, ()>,
+) -> Result<Vec<u8>, ()> {
+    let calls = <Vec<(u8, Vec<u8>, Vec<u8>)>>::decode(&mut calls).map_err(|_| ())?;
+    let results = calls
+        .into_iter()
+        .map(|(op, a, b)| dispatch(op, a, b))
+        .collect::<Result<Vec<_>, ()>>()?;
+    Ok(results.encode())
+}
+
+/// Number of calls of an encoded batch, for metering.
+pub fn batch_len(calls: &[u8]) -> u64 {
+    codec::Compact::<u32>::decode(&mut &calls[..]).map_or(0, |len| len.0 as u64)
+}
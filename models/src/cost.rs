@@ -0,0 +1,48 @@
+//! Cost estimates of the elliptic curve host calls.
+//!
+//! Each curve crate provides a `HostCostModel` seeded from the benchmark figures
+//! published in the README, which can be used to compute a weight before dispatch.
+
+use crate::metering::{Operation, Usage};
+
+/// Cost of a host call, in nanoseconds, linear in its number of arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinearCost {
+    /// Cost of every call.
+    pub base: u64,
+    /// Cost of every argument, i.e. MSM base or Miller loop pair.
+    pub per_arg: u64,
+}
+
+impl LinearCost {
+    /// Cost of `base` per call and `per_arg` per argument.
+    pub const fn new(base: u64, per_arg: u64) -> Self {
+        LinearCost { base, per_arg }
+    }
+
+    /// Cost of a call with `args` arguments.
+    pub const fn cost(&self, args: u64) -> u64 {
+        self.base.saturating_add(self.per_arg.saturating_mul(args))
+    }
+}
+
+/// Estimated cost of the host calls of a curve.
+pub trait CostModel {
+    /// Cost of the `op` host call, `None` if the curve doesn't provide it or it
+    /// has no benchmark figure.
+    fn cost_of(op: Operation) -> Option<LinearCost>;
+
+    /// Cost of an `op` host call with `args` arguments.
+    fn cost(op: Operation, args: u64) -> Option<u64> {
+        Self::cost_of(op).map(|cost| cost.cost(args))
+    }
+
+    /// Cost of the `usage` recorded for `op`, e.g. by a `Metered` host.
+    fn usage_cost(op: Operation, usage: Usage) -> Option<u64> {
+        Self::cost_of(op).map(|cost| {
+            cost.base
+                .saturating_mul(usage.calls)
+                .saturating_add(cost.per_arg.saturating_mul(usage.args))
+        })
+    }
+}
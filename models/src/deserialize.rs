@@ -0,0 +1,73 @@
+//! Batched deserialization of compressed points.
+//!
+//! Deserializing a compressed point takes a square root to recover its second
+//! coordinate and a subgroup membership check, both costly in WASM. The
+//! `*_deserialize_*` host functions instead take the compressed encoding of a
+//! whole vector of points and return them decompressed and checked.
+//!
+//! Pairing engines implement [`DeserializeBatch`] for their two groups, which
+//! decoders of larger structures such as verifying keys or signature sets feed
+//! with the points they gathered in [`CompressedPoints`], one host call per group.
+
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_serialize::{Read, SerializationError};
+use ark_std::{marker::PhantomData, vec::Vec};
+use derivative::Derivative;
+
+/// Pairing engines deserializing vectors of compressed points at once.
+pub trait DeserializeBatch: Pairing {
+    /// Deserialize `compressed`, the compressed encoding of a vector of points of G1,
+    /// checking that they are on the curve and in the prime order subgroup.
+    fn deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<Self::G1Affine>, SerializationError>;
+
+    /// Deserialize `compressed`, the compressed encoding of a vector of points of G2,
+    /// checking that they are on the curve and in the prime order subgroup.
+    fn deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<Self::G2Affine>, SerializationError>;
+}
+
+/// Compressed encoding of a vector of points of `A`, gathered from one or more
+/// readers.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Default(bound = ""), Debug(bound = ""))]
+pub struct CompressedPoints<A: AffineRepr> {
+    len: u64,
+    points: Vec<u8>,
+    _point: PhantomData<fn() -> A>,
+}
+
+impl<A: AffineRepr> CompressedPoints<A> {
+    /// No points.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of points read.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether no point was read.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Read `len` compressed points from `reader`, without decompressing them.
+    pub fn read<R: Read>(&mut self, mut reader: R, len: u64) -> Result<(), SerializationError> {
+        let size = A::generator().compressed_size();
+        for _ in 0..len {
+            let start = self.points.len();
+            self.points.resize(start + size, 0);
+            reader.read_exact(&mut self.points[start..])?;
+        }
+        self.len += len;
+        Ok(())
+    }
+
+    /// Compressed encoding of the vector of the points read, in order.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(8 + self.points.len());
+        encoded.extend_from_slice(&self.len.to_le_bytes());
+        encoded.extend_from_slice(&self.points);
+        encoded
+    }
+}
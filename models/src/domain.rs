@@ -0,0 +1,229 @@
+//! Radix-2 evaluation domains computing their FFTs with the host.
+//!
+//! [`Domain`] implements [`EvaluationDomain`] on top of [`Radix2EvaluationDomain`].
+//! Its [`Domain::host_fft`], [`Domain::host_ifft`], [`Domain::host_fft_in_place`]
+//! and [`Domain::host_ifft_in_place`] compute the FFTs of field element vectors
+//! with the host, and [`EvaluationDomain::evaluate_all_lagrange_coefficients`] its
+//! batch inversion, falling back to software when the host fails.
+//!
+//! The [`EvaluationDomain`] FFTs, including those of generic code over it, are
+//! computed in software: they may be over any [`DomainCoeff`], which the host
+//! cannot receive, and cannot be told apart from field elements without `T: 'static`.
+
+use ark_ff::{batch_inversion, FftField};
+use ark_poly::{
+    domain::{radix2::Elements, DomainCoeff},
+    EvaluationDomain, Radix2EvaluationDomain,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::{marker::PhantomData, rand::Rng, vec::Vec};
+use derivative::Derivative;
+
+use crate::HostCallError;
+
+/// Host functions computing FFTs and batch inversions over `F`.
+pub trait FftHost<F: FftField>: 'static {
+    /// Evaluations of the polynomial with coefficients `coeffs` over the coset of
+    /// the subgroup of order `coeffs.len()`, a power of two, by `offset`.
+    fn fft(coeffs: &[F], offset: F) -> Result<Vec<F>, HostCallError>;
+
+    /// Coefficients of the polynomial with evaluations `evals` over the coset of
+    /// the subgroup of order `evals.len()`, a power of two, by `offset`.
+    fn ifft(evals: &[F], offset: F) -> Result<Vec<F>, HostCallError>;
+
+    /// Inverses of `values`, zero being left as is.
+    fn batch_inverse(values: &[F]) -> Result<Vec<F>, HostCallError>;
+}
+
+/// Invert every nonzero element of `values` with the host, falling back to
+/// software when it fails.
+pub fn batch_inverse<F: FftField, H: FftHost<F>>(values: &mut [F]) {
+    match H::batch_inverse(values) {
+        Ok(inverses) if inverses.len() == values.len() => values.copy_from_slice(&inverses),
+        _ => batch_inversion(values),
+    }
+}
+
+/// Radix-2 evaluation domain over `F` computing its FFTs with the host `H`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = ""),
+    Clone(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = ""),
+    Hash(bound = ""),
+    Debug(bound = "")
+)]
+pub struct Domain<F: FftField, H: FftHost<F>> {
+    inner: Radix2EvaluationDomain<F>,
+    _host: PhantomData<fn() -> H>,
+}
+
+impl<F: FftField, H: FftHost<F>> Domain<F, H> {
+    fn wrap(inner: Radix2EvaluationDomain<F>) -> Self {
+        Domain {
+            inner,
+            _host: PhantomData,
+        }
+    }
+
+    /// Evaluations over the domain of the polynomial with coefficients `coeffs`,
+    /// computed with the host.
+    pub fn host_fft(&self, coeffs: &[F]) -> Vec<F> {
+        let mut coeffs = coeffs.to_vec();
+        self.host_fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Coefficients of the polynomial with evaluations `evals` over the domain,
+    /// computed with the host.
+    pub fn host_ifft(&self, evals: &[F]) -> Vec<F> {
+        let mut evals = evals.to_vec();
+        self.host_ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// In place version of [`Domain::host_fft`].
+    pub fn host_fft_in_place(&self, coeffs: &mut Vec<F>) {
+        if coeffs.len() > self.size() {
+            // Coefficients beyond the domain size wrap around, as in software.
+            return self.inner.fft_in_place(coeffs);
+        }
+        coeffs.resize(self.size(), F::zero());
+        match H::fft(coeffs, self.coset_offset()) {
+            Ok(evals) if evals.len() == coeffs.len() => *coeffs = evals,
+            _ => self.inner.fft_in_place(coeffs),
+        }
+    }
+
+    /// In place version of [`Domain::host_ifft`].
+    pub fn host_ifft_in_place(&self, evals: &mut Vec<F>) {
+        if evals.len() > self.size() {
+            return self.inner.ifft_in_place(evals);
+        }
+        evals.resize(self.size(), F::zero());
+        match H::ifft(evals, self.coset_offset()) {
+            Ok(coeffs) if coeffs.len() == evals.len() => *evals = coeffs,
+            _ => self.inner.ifft_in_place(evals),
+        }
+    }
+}
+
+impl<F: FftField, H: FftHost<F>> EvaluationDomain<F> for Domain<F, H> {
+    type Elements = Elements<F>;
+
+    fn sample_element_outside_domain<R: Rng>(&self, rng: &mut R) -> F {
+        self.inner.sample_element_outside_domain(rng)
+    }
+
+    fn new(num_coeffs: usize) -> Option<Self> {
+        Radix2EvaluationDomain::new(num_coeffs).map(Self::wrap)
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        self.inner.get_coset(offset).map(Self::wrap)
+    }
+
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        Radix2EvaluationDomain::<F>::compute_size_of_domain(num_coeffs)
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn log_size_of_group(&self) -> u64 {
+        self.inner.log_size_of_group()
+    }
+
+    fn size_inv(&self) -> F {
+        self.inner.size_inv()
+    }
+
+    fn group_gen(&self) -> F {
+        self.inner.group_gen()
+    }
+
+    fn group_gen_inv(&self) -> F {
+        self.inner.group_gen_inv()
+    }
+
+    fn coset_offset(&self) -> F {
+        self.inner.coset_offset()
+    }
+
+    fn coset_offset_inv(&self) -> F {
+        self.inner.coset_offset_inv()
+    }
+
+    fn coset_offset_pow_size(&self) -> F {
+        self.inner.coset_offset_pow_size()
+    }
+
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        self.inner.fft_in_place(coeffs)
+    }
+
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        self.inner.ifft_in_place(evals)
+    }
+
+    fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
+        // With xᵢ the domain elements and Z(X) = Xⁿ - hⁿ the vanishing polynomial,
+        // Lᵢ(τ) = Z(τ)·xᵢ / (n·hⁿ·(τ - xᵢ)).
+        let z = self.evaluate_vanishing_polynomial(tau);
+        if z.is_zero() {
+            return self
+                .elements()
+                .map(|x| if x == tau { F::one() } else { F::zero() })
+                .collect();
+        }
+        let elements = self.elements().collect::<Vec<_>>();
+        let mut inverses = elements.iter().map(|x| tau - x).collect::<Vec<_>>();
+        inverses.push(self.size_as_field_element() * self.coset_offset_pow_size());
+        batch_inverse::<F, H>(&mut inverses);
+        let c = z * inverses.pop().expect("pushed above");
+        elements
+            .iter()
+            .zip(inverses)
+            .map(|(x, inverse)| c * x * inverse)
+            .collect()
+    }
+
+    fn elements(&self) -> Elements<F> {
+        self.inner.elements()
+    }
+}
+
+impl<F: FftField, H: FftHost<F>> Valid for Domain<F, H> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.inner.check()
+    }
+}
+
+impl<F: FftField, H: FftHost<F>> CanonicalSerialize for Domain<F, H> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.inner.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.inner.serialized_size(compress)
+    }
+}
+
+impl<F: FftField, H: FftHost<F>> CanonicalDeserialize for Domain<F, H> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Radix2EvaluationDomain::deserialize_with_mode(reader, compress, validate).map(Self::wrap)
+    }
+}
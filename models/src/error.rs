@@ -0,0 +1,25 @@
+use core::fmt;
+
+/// Error returned by the fallible host call entry points of the curve configs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HostCallError {
+    /// The host function rejected its input.
+    HostRejected,
+    /// The host function output could not be decoded.
+    DecodeFailed,
+    /// The input slices have different lengths.
+    LengthMismatch,
+}
+
+impl fmt::Display for HostCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostCallError::HostRejected => write!(f, "host function rejected the input"),
+            HostCallError::DecodeFailed => write!(f, "failed to decode the host function output"),
+            HostCallError::LengthMismatch => write!(f, "input lengths do not match"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HostCallError {}
@@ -0,0 +1,179 @@
+//! Fixed-base scalar multiplication with precomputed tables.
+//!
+//! [`FixedBase`] splits scalars into windows of `w` bits and keeps, for every
+//! window `i`, the multiples `j·2^(w·i)·P` for `j` in `1..2^w`, so that a scalar
+//! multiplication of `P` takes one mixed addition per window and no doubling. It is
+//! worth it for bases multiplied over and over, such as the curve generators used
+//! by key generation, signing and commitments.
+//!
+//! Tables are built with additions only, in WASM or off-chain, in which case they
+//! can be shipped with their canonical encoding. Deserializing with validation
+//! rebuilds the table to check it.
+//!
+//! [`FixedBase`] is software only: building a table, normalising its rows and
+//! multiplying with it never call the host, even for the groups of host curves.
+//! Bases multiplied too rarely to amortise their table are better multiplied by
+//! the host directly.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::vec::Vec;
+use derivative::Derivative;
+
+/// Window size of [`FixedBase::new`], for a table of `15·⌈bits / 4⌉` points.
+pub const DEFAULT_WINDOW: usize = 4;
+
+/// Largest supported window size.
+pub const MAX_WINDOW: usize = 8;
+
+/// Precomputed multiples of a fixed base of `G`, computed in software.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = ""),
+    Debug(bound = "")
+)]
+pub struct FixedBase<G: CurveGroup> {
+    window: usize,
+    table: Vec<Vec<G::Affine>>,
+}
+
+impl<G: CurveGroup> FixedBase<G> {
+    /// Table of the multiples of `base` with [`DEFAULT_WINDOW`].
+    pub fn new(base: G) -> Self {
+        Self::with_window(base, DEFAULT_WINDOW)
+    }
+
+    /// Table of the multiples of the generator of `G` with [`DEFAULT_WINDOW`].
+    pub fn generator() -> Self {
+        Self::new(G::generator())
+    }
+
+    /// Table of the multiples of `base` with windows of `window` bits, between 1
+    /// and [`MAX_WINDOW`].
+    pub fn with_window(base: G, window: usize) -> Self {
+        assert!(
+            (1..=MAX_WINDOW).contains(&window),
+            "window size out of range"
+        );
+        let mut table = Vec::with_capacity(num_windows::<G>(window));
+        let mut row_base = base;
+        for _ in 0..num_windows::<G>(window) {
+            let mut row = Vec::with_capacity((1 << window) - 1);
+            let mut multiple = row_base;
+            for _ in 1..1usize << window {
+                row.push(multiple);
+                multiple += row_base;
+            }
+            table.push(G::normalize_batch(&row));
+            row_base = multiple;
+        }
+        FixedBase { window, table }
+    }
+
+    /// Window size of the table.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Base of the table.
+    pub fn base(&self) -> G::Affine {
+        self.table[0][0]
+    }
+
+    /// Multiply the base by `scalar`.
+    pub fn mul(&self, scalar: &G::ScalarField) -> G {
+        let bits = scalar.into_bigint().to_bits_le();
+        let mut result = G::zero();
+        for (row, digit) in self.table.iter().zip(bits.chunks(self.window)) {
+            let digit = digit
+                .iter()
+                .rev()
+                .fold(0, |digit, bit| (digit << 1) | *bit as usize);
+            if digit != 0 {
+                result += &row[digit - 1];
+            }
+        }
+        result
+    }
+
+    /// Multiply the base by every scalar of `scalars`.
+    pub fn batch_mul(&self, scalars: &[G::ScalarField]) -> Vec<G::Affine> {
+        let results = scalars
+            .iter()
+            .map(|scalar| self.mul(scalar))
+            .collect::<Vec<_>>();
+        G::normalize_batch(&results)
+    }
+
+    fn check_shape(&self) -> Result<(), SerializationError> {
+        if !(1..=MAX_WINDOW).contains(&self.window)
+            || self.table.len() != num_windows::<G>(self.window)
+            || self
+                .table
+                .iter()
+                .any(|row| row.len() != (1 << self.window) - 1)
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+/// Number of windows of `window` bits covering the scalars of `G`.
+fn num_windows<G: CurveGroup>(window: usize) -> usize {
+    let bits = G::ScalarField::MODULUS_BIT_SIZE as usize;
+    (bits + window - 1) / window
+}
+
+impl<G: CurveGroup> Valid for FixedBase<G> {
+    /// Check the table against the one rebuilt from its base, which costs as much
+    /// as building it: only tables from trusted sources should skip validation.
+    fn check(&self) -> Result<(), SerializationError> {
+        self.check_shape()?;
+        self.base().check()?;
+        if *self != Self::with_window(self.base().into_group(), self.window) {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<G: CurveGroup> CanonicalSerialize for FixedBase<G> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        (self.window as u8).serialize_with_mode(&mut writer, compress)?;
+        self.table.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        1 + self.table.serialized_size(compress)
+    }
+}
+
+impl<G: CurveGroup> CanonicalDeserialize for FixedBase<G> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let window = u8::deserialize_with_mode(&mut reader, compress, validate)? as usize;
+        let table = Vec::deserialize_with_mode(reader, compress, Validate::No)?;
+        let result = FixedBase { window, table };
+        // The shape is checked regardless of `validate`, as multiplications index
+        // the table by the scalar digits.
+        result.check_shape()?;
+        if let Validate::Yes = validate {
+            result.check()?;
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,84 @@
+//! GLV scalar multiplication.
+//!
+//! On curves with an efficient endomorphism `ψ` acting as the multiplication by `λ`
+//! on the prime order subgroup, `k·P = k1·P + k2·ψ(P)` for `k = k1 + λ·k2 mod r`,
+//! with `k1` and `k2` of about half the size of `r`. Interleaving both halves needs
+//! half as many doublings as a plain double and add, which is worth it whenever a
+//! scalar multiplication runs in WASM rather than on the host.
+//!
+//! This only holds for points in the prime order subgroup.
+
+use ark_ec::{CurveConfig, CurveGroup};
+use num_bigint::{BigInt, Sign};
+
+/// GLV parameters of a curve.
+pub trait GlvConfig: CurveConfig {
+    /// Eigenvalue of the endomorphism on the prime order subgroup.
+    const LAMBDA: Self::ScalarField;
+
+    /// Reduced basis `(n11, n12), (n21, n22)` of the lattice of the `(a, b)` with
+    /// `a + λ·b = 0 mod r`, as signs, `true` for negative, and magnitudes.
+    const BASIS: [(bool, u128); 4];
+}
+
+/// Decompose `scalar` into `k1 + λ·k2`, returning the signs, `true` for negative,
+/// and the magnitudes of `k1` and `k2`.
+pub fn decompose<C: GlvConfig>(scalar: &C::ScalarField) -> [(bool, u128); 2] {
+    let [n11, n12, n21, n22] = C::BASIS.map(|(negative, n)| {
+        let n = BigInt::from(n);
+        if negative {
+            -n
+        } else {
+            n
+        }
+    });
+    let k = BigInt::from_biguint(Sign::Plus, (*scalar).into());
+    let det = &n11 * &n22 - &n12 * &n21;
+
+    // (k, 0) = β1·(n11, n12) + β2·(n21, n22) with β1 = k·n22 / det and
+    // β2 = -k·n12 / det, rounded to the closest lattice point.
+    let b1 = round_div(&k * &n22, &det);
+    let b2 = round_div(-&k * &n12, &det);
+    let k1 = k - &b1 * &n11 - &b2 * &n21;
+    let k2 = -b1 * &n12 - b2 * &n22;
+
+    [k1, k2].map(|k| {
+        let (sign, magnitude) = k.into_parts();
+        (
+            sign == Sign::Minus,
+            u128::try_from(&magnitude).expect("the decomposition is short"),
+        )
+    })
+}
+
+/// Closest integer to `a / b`.
+fn round_div(a: BigInt, b: &BigInt) -> BigInt {
+    let negative = (a.sign() == Sign::Minus) != (b.sign() == Sign::Minus);
+    let (a, b) = (a.magnitude().clone(), b.magnitude());
+    let quotient = (a * 2u8 + b) / (b * 2u8);
+    BigInt::from_biguint(if negative { Sign::Minus } else { Sign::Plus }, quotient)
+}
+
+/// Multiply `base` by `scalar` with the GLV method, `endomorphism` being the image
+/// of `base` by the endomorphism. `base` must be in the prime order subgroup.
+pub fn mul<G: CurveGroup>(base: &G, endomorphism: &G, scalar: &G::ScalarField) -> G
+where
+    G::Config: GlvConfig,
+{
+    let [(neg1, k1), (neg2, k2)] = decompose::<G::Config>(scalar);
+    let p1 = if neg1 { -*base } else { *base };
+    let p2 = if neg2 { -*endomorphism } else { *endomorphism };
+    let points = G::normalize_batch(&[p1, p2, p1 + p2]);
+
+    let mut result = G::zero();
+    for i in (0..128 - (k1 | k2).leading_zeros()).rev() {
+        result.double_in_place();
+        match ((k1 >> i) & 1, (k2 >> i) & 1) {
+            (1, 1) => result += &points[2],
+            (1, 0) => result += &points[0],
+            (0, 1) => result += &points[1],
+            _ => {}
+        }
+    }
+    result
+}
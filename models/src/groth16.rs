@@ -0,0 +1,240 @@
+//! Groth16 proof verification.
+//!
+//! The public inputs are combined with a single multi scalar multiplication on G1 and
+//! the proof is checked with a single pairing check, both performed by the host for
+//! the host accelerated curves. Verifying keys and proofs use the `ark-groth16`
+//! serialization.
+
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::vec::Vec;
+
+use crate::{
+    deserialize::{CompressedPoints, DeserializeBatch},
+    models::PairingCheck,
+    HostCallError, TryMsm,
+};
+
+/// Groth16 proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof<E: Pairing> {
+    pub a: E::G1Affine,
+    pub b: E::G2Affine,
+    pub c: E::G1Affine,
+}
+
+/// Groth16 verifying key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey<E: Pairing> {
+    pub alpha_g1: E::G1Affine,
+    pub beta_g2: E::G2Affine,
+    pub gamma_g2: E::G2Affine,
+    pub delta_g2: E::G2Affine,
+    /// Bases of the public inputs combination, the first one being for the constant term.
+    pub gamma_abc_g1: Vec<E::G1Affine>,
+}
+
+impl<E: DeserializeBatch> VerifyingKey<E> {
+    /// Deserialize a compressed verifying key, decompressing and checking the points
+    /// of each group at once with [`DeserializeBatch`].
+    pub fn deserialize_batch<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let mut g1 = CompressedPoints::<E::G1Affine>::new();
+        let mut g2 = CompressedPoints::<E::G2Affine>::new();
+        g1.read(&mut reader, 1)?;
+        g2.read(&mut reader, 3)?;
+        let len = u64::deserialize_compressed(&mut reader)?;
+        g1.read(&mut reader, len)?;
+
+        let mut g1 = E::deserialize_g1_batch(&g1.encode())?.into_iter();
+        let mut g2 = E::deserialize_g2_batch(&g2.encode())?.into_iter();
+        let mut next_g2 = || g2.next().ok_or(SerializationError::InvalidData);
+        Ok(VerifyingKey {
+            alpha_g1: g1.next().ok_or(SerializationError::InvalidData)?,
+            beta_g2: next_g2()?,
+            gamma_g2: next_g2()?,
+            delta_g2: next_g2()?,
+            gamma_abc_g1: g1.collect(),
+        })
+    }
+}
+
+/// Groth16 verifying key prepared for the pairing check.
+#[derive(Clone, Debug)]
+pub struct PreparedVerifyingKey<E: Pairing> {
+    pub vk: VerifyingKey<E>,
+    neg_alpha_g1: E::G1Prepared,
+    beta_g2: E::G2Prepared,
+    gamma_g2: E::G2Prepared,
+    delta_g2: E::G2Prepared,
+}
+
+impl<E: Pairing> From<VerifyingKey<E>> for PreparedVerifyingKey<E> {
+    fn from(vk: VerifyingKey<E>) -> Self {
+        prepare_verifying_key(vk)
+    }
+}
+
+/// Prepare `vk` for the pairing check.
+pub fn prepare_verifying_key<E: Pairing>(vk: VerifyingKey<E>) -> PreparedVerifyingKey<E> {
+    PreparedVerifyingKey {
+        neg_alpha_g1: (-vk.alpha_g1.into_group()).into_affine().into(),
+        beta_g2: vk.beta_g2.into(),
+        gamma_g2: vk.gamma_g2.into(),
+        delta_g2: vk.delta_g2.into(),
+        vk,
+    }
+}
+
+/// Combine `public_inputs` with the bases of `pvk`.
+pub fn prepare_inputs<E: Pairing>(
+    pvk: &PreparedVerifyingKey<E>,
+    public_inputs: &[E::ScalarField],
+) -> Result<E::G1, HostCallError>
+where
+    E::G1: TryMsm,
+{
+    let (constant, bases) = pvk
+        .vk
+        .gamma_abc_g1
+        .split_first()
+        .ok_or(HostCallError::LengthMismatch)?;
+    if bases.len() != public_inputs.len() {
+        return Err(HostCallError::LengthMismatch);
+    }
+    let inputs = E::G1::try_msm(bases, public_inputs)?;
+    Ok(inputs + constant)
+}
+
+/// Verify `proof` for `public_inputs`, already combined with [`prepare_inputs`].
+pub fn verify_proof_with_prepared_inputs<E: PairingCheck>(
+    pvk: &PreparedVerifyingKey<E>,
+    proof: &Proof<E>,
+    prepared_inputs: &E::G1,
+) -> bool {
+    E::multi_pairing_is_one(
+        [
+            proof.a.into(),
+            pvk.neg_alpha_g1.clone(),
+            (-*prepared_inputs).into_affine().into(),
+            (-proof.c.into_group()).into_affine().into(),
+        ],
+        [
+            proof.b.into(),
+            pvk.beta_g2.clone(),
+            pvk.gamma_g2.clone(),
+            pvk.delta_g2.clone(),
+        ],
+    )
+}
+
+/// Verify `proof` for `public_inputs`.
+pub fn verify_proof<E: PairingCheck>(
+    pvk: &PreparedVerifyingKey<E>,
+    proof: &Proof<E>,
+    public_inputs: &[E::ScalarField],
+) -> Result<bool, HostCallError>
+where
+    E::G1: TryMsm,
+{
+    let prepared_inputs = prepare_inputs(pvk, public_inputs)?;
+    Ok(verify_proof_with_prepared_inputs(
+        pvk,
+        proof,
+        &prepared_inputs,
+    ))
+}
+
+impl<E: Pairing> Valid for Proof<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.a.check()?;
+        self.b.check()?;
+        self.c.check()
+    }
+}
+
+impl<E: Pairing> CanonicalSerialize for Proof<E> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.a.serialize_with_mode(&mut writer, compress)?;
+        self.b.serialize_with_mode(&mut writer, compress)?;
+        self.c.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.a.serialized_size(compress)
+            + self.b.serialized_size(compress)
+            + self.c.serialized_size(compress)
+    }
+}
+
+impl<E: Pairing> CanonicalDeserialize for Proof<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Proof {
+            a: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            b: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            c: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+impl<E: Pairing> Valid for VerifyingKey<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.alpha_g1.check()?;
+        self.beta_g2.check()?;
+        self.gamma_g2.check()?;
+        self.delta_g2.check()?;
+        self.gamma_abc_g1.check()
+    }
+}
+
+impl<E: Pairing> CanonicalSerialize for VerifyingKey<E> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.alpha_g1.serialize_with_mode(&mut writer, compress)?;
+        self.beta_g2.serialize_with_mode(&mut writer, compress)?;
+        self.gamma_g2.serialize_with_mode(&mut writer, compress)?;
+        self.delta_g2.serialize_with_mode(&mut writer, compress)?;
+        self.gamma_abc_g1.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.alpha_g1.serialized_size(compress)
+            + self.beta_g2.serialized_size(compress)
+            + self.gamma_g2.serialized_size(compress)
+            + self.delta_g2.serialized_size(compress)
+            + self.gamma_abc_g1.serialized_size(compress)
+    }
+}
+
+impl<E: Pairing> CanonicalDeserialize for VerifyingKey<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(VerifyingKey {
+            alpha_g1: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            beta_g2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            gamma_g2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            delta_g2: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            gamma_abc_g1: CanonicalDeserialize::deserialize_with_mode(
+                &mut reader,
+                compress,
+                validate,
+            )?,
+        })
+    }
+}
@@ -0,0 +1,213 @@
+//! KZG polynomial commitment opening verification.
+//!
+//! The pairing inputs are combined with multi scalar multiplications and every
+//! verification is a single pairing check, both performed by the host for the host
+//! accelerated curves.
+
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{Field, One, Zero};
+use ark_std::{rand::RngCore, vec, vec::Vec, UniformRand};
+
+use crate::{models::PairingCheck, HostCallError, TryMsm};
+
+/// KZG verifier key, i.e. a prefix of the powers of the trapdoor `τ`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifierKey<E: Pairing> {
+    /// `[τ^i]₁`, the generator and as many powers as points opened at once, minus one.
+    pub powers_of_g1: Vec<E::G1Affine>,
+    /// `[τ^i]₂`, the generator and as many powers as points opened at once.
+    pub powers_of_g2: Vec<E::G2Affine>,
+}
+
+impl<E: Pairing> VerifierKey<E> {
+    /// Verifier key for single point openings.
+    pub fn new(g1: E::G1Affine, g2: E::G2Affine, tau_g2: E::G2Affine) -> Self {
+        VerifierKey {
+            powers_of_g1: vec![g1],
+            powers_of_g2: vec![g2, tau_g2],
+        }
+    }
+
+    fn g1(&self) -> Result<E::G1Affine, HostCallError> {
+        self.powers_of_g1
+            .first()
+            .copied()
+            .ok_or(HostCallError::LengthMismatch)
+    }
+
+    fn g2_and_tau_g2(&self) -> Result<(E::G2Affine, E::G2Affine), HostCallError> {
+        match self.powers_of_g2.get(..2) {
+            Some([g2, tau_g2]) => Ok((*g2, *tau_g2)),
+            _ => Err(HostCallError::LengthMismatch),
+        }
+    }
+}
+
+/// Opening of a committed polynomial at a single point.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Opening<E: Pairing> {
+    pub commitment: E::G1Affine,
+    pub point: E::ScalarField,
+    pub value: E::ScalarField,
+    pub proof: E::G1Affine,
+}
+
+/// Verify that the polynomial committed to by `commitment` evaluates to `value` at
+/// `point`.
+pub fn verify<E: PairingCheck>(
+    vk: &VerifierKey<E>,
+    commitment: &E::G1Affine,
+    point: E::ScalarField,
+    value: E::ScalarField,
+    proof: &E::G1Affine,
+) -> Result<bool, HostCallError>
+where
+    E::G1: TryMsm,
+{
+    let (g2, tau_g2) = vk.g2_and_tau_g2()?;
+    // e(C - v·G + z·π, H) = e(π, τH)
+    let lhs = E::G1::try_msm(
+        &[*commitment, vk.g1()?, *proof],
+        &[E::ScalarField::one(), -value, point],
+    )?;
+    Ok(E::multi_pairing_is_one(
+        [lhs.into_affine(), (-proof.into_group()).into_affine()],
+        [g2, tau_g2],
+    ))
+}
+
+/// Verify that the polynomial committed to by `commitment` evaluates to `values` at
+/// `points`, the points being distinct.
+///
+/// `proof` commits to `(p - I) / Z`, where `I` interpolates the values and `Z`
+/// vanishes at the points. Openings at repeated points never verify.
+pub fn verify_multi<E: PairingCheck>(
+    vk: &VerifierKey<E>,
+    commitment: &E::G1Affine,
+    points: &[E::ScalarField],
+    values: &[E::ScalarField],
+    proof: &E::G1Affine,
+) -> Result<bool, HostCallError>
+where
+    E::G1: TryMsm,
+    E::G2: TryMsm,
+{
+    if points.len() != values.len()
+        || points.is_empty()
+        || vk.powers_of_g1.len() < points.len()
+        || vk.powers_of_g2.len() <= points.len()
+    {
+        return Err(HostCallError::LengthMismatch);
+    }
+    let interpolation = match interpolate(points, values) {
+        Some(interpolation) => interpolation,
+        None => return Ok(false),
+    };
+    let vanishing = vanishing_polynomial(points);
+
+    // e(C - [I(τ)]₁, H) = e(π, [Z(τ)]₂)
+    let mut bases = Vec::with_capacity(points.len() + 1);
+    bases.push(*commitment);
+    bases.extend_from_slice(&vk.powers_of_g1[..points.len()]);
+    let mut scalars = Vec::with_capacity(points.len() + 1);
+    scalars.push(E::ScalarField::one());
+    scalars.extend(interpolation.into_iter().map(|c| -c));
+    let lhs = E::G1::try_msm(&bases, &scalars)?;
+    let vanishing_g2 = E::G2::try_msm(&vk.powers_of_g2[..vanishing.len()], &vanishing)?;
+
+    Ok(E::multi_pairing_is_one(
+        [lhs.into_affine(), (-proof.into_group()).into_affine()],
+        [vk.powers_of_g2[0], vanishing_g2.into_affine()],
+    ))
+}
+
+/// Verify `openings` at once, combining them with random powers drawn from `rng`.
+pub fn verify_batch<E: PairingCheck, R: RngCore>(
+    vk: &VerifierKey<E>,
+    openings: &[Opening<E>],
+    rng: &mut R,
+) -> Result<bool, HostCallError>
+where
+    E::G1: TryMsm,
+{
+    verify_batch_with_challenge(vk, openings, E::ScalarField::rand(rng))
+}
+
+/// Verify `openings` at once, combining them with the powers of `r`, which must be
+/// unpredictable to whoever produced the openings, e.g. derived with Fiat-Shamir.
+pub fn verify_batch_with_challenge<E: PairingCheck>(
+    vk: &VerifierKey<E>,
+    openings: &[Opening<E>],
+    r: E::ScalarField,
+) -> Result<bool, HostCallError>
+where
+    E::G1: TryMsm,
+{
+    let (g2, tau_g2) = vk.g2_and_tau_g2()?;
+
+    // e(Σ rⁱ (Cᵢ - vᵢ·G + zᵢ·πᵢ), H) = e(Σ rⁱ πᵢ, τH)
+    let mut bases = Vec::with_capacity(2 * openings.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * openings.len() + 1);
+    let mut proofs = Vec::with_capacity(openings.len());
+    let mut powers = Vec::with_capacity(openings.len());
+    let mut value = E::ScalarField::zero();
+    let mut power = E::ScalarField::one();
+    for opening in openings {
+        bases.push(opening.commitment);
+        scalars.push(power);
+        bases.push(opening.proof);
+        scalars.push(power * opening.point);
+        value += power * opening.value;
+        proofs.push(opening.proof);
+        powers.push(power);
+        power *= r;
+    }
+    bases.push(vk.g1()?);
+    scalars.push(-value);
+
+    let lhs = E::G1::try_msm(&bases, &scalars)?;
+    let proof = E::G1::try_msm(&proofs, &powers)?;
+    Ok(E::multi_pairing_is_one(
+        [lhs.into_affine(), (-proof).into_affine()],
+        [g2, tau_g2],
+    ))
+}
+
+/// Coefficients of the polynomial vanishing at `points`.
+fn vanishing_polynomial<F: Field>(points: &[F]) -> Vec<F> {
+    let mut coeffs = vec![F::one()];
+    for point in points {
+        coeffs.insert(0, F::zero());
+        for i in 0..coeffs.len() - 1 {
+            let shifted = coeffs[i + 1] * point;
+            coeffs[i] -= shifted;
+        }
+    }
+    coeffs
+}
+
+/// Coefficients of the polynomial of degree less than `points.len()` taking `values`
+/// at `points`, `None` if the points are not distinct.
+fn interpolate<F: Field>(points: &[F], values: &[F]) -> Option<Vec<F>> {
+    let vanishing = vanishing_polynomial(points);
+    let mut coeffs = vec![F::zero(); points.len()];
+    for (i, (point, value)) in points.iter().zip(values).enumerate() {
+        // Z / (X - zᵢ) by synthetic division.
+        let mut quotient = vec![F::zero(); points.len()];
+        let mut carry = F::zero();
+        for j in (0..points.len()).rev() {
+            carry = vanishing[j + 1] + carry * point;
+            quotient[j] = carry;
+        }
+        let denominator = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(F::one(), |acc, (_, other)| acc * (*point - other));
+        let scale = *value * denominator.inverse()?;
+        for (coeff, q) in coeffs.iter_mut().zip(quotient) {
+            *coeff += q * scale;
+        }
+    }
+    Some(coeffs)
+}
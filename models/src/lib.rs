@@ -4,5 +4,25 @@ pub use ark_ec::{
     scalar_mul, scalar_mul::*, twisted_edwards, twisted_edwards::*, AffineRepr, CurveGroup, Group,
     VariableBaseMSM,
 };
+pub mod batch;
+pub mod cost;
+pub mod deserialize;
+pub mod domain;
+pub mod error;
+pub mod fixed_base;
+pub mod glv;
+pub mod groth16;
+pub mod kzg;
+pub mod metering;
 pub mod models;
+pub mod msm;
+pub mod pedersen;
+pub mod registry;
+pub mod schnorr;
+pub mod small_msm;
+pub mod software;
+pub mod torus;
+
+pub use error::HostCallError;
 pub use models::*;
+pub use msm::TryMsm;
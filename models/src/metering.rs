@@ -0,0 +1,231 @@
+//! Metering of the elliptic curve host calls.
+//!
+//! Each curve crate provides a `Metered<H>` host functions wrapper recording the
+//! work performed by every host call into a [`Meter`] of its [`Meters`], one for
+//! every `H`, which can be used to charge weight proportional to what a high level
+//! arkworks call actually turned into.
+
+#![allow(clippy::result_unit_err)]
+
+use ark_std::{any::TypeId, boxed::Box, vec::Vec};
+use core::sync::atomic::{AtomicU64, Ordering};
+use spin::Mutex;
+
+macro_rules! operations {
+    ($($op:ident),* $(,)?) => {
+        /// Elliptic curve host call operations.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Operation {
+            $($op,)*
+        }
+
+        impl Operation {
+            /// All the host call operations.
+            pub const ALL: [Operation; OPERATIONS] = [$(Operation::$op,)*];
+        }
+
+        const OPERATIONS: usize = [$(stringify!($op),)*].len();
+    };
+}
+
+operations! {
+    MultiMillerLoop,
+    FinalExponentiation,
+    PairingCheck,
+    MsmG1,
+    MsmG2,
+    MulProjectiveG1,
+    MulProjectiveG2,
+    TeMsm,
+    SwMsm,
+    TeMulProjective,
+    SwMulProjective,
+    HashToG1,
+    HashToG2,
+    MulGt,
+    MsmGt,
+    Fft,
+    Ifft,
+    BatchInverse,
+    RegisterBases,
+    MsmWithHandle,
+    MsmSmall,
+    Batch,
+    Normalize,
+    Deserialize,
+}
+
+/// Usage recorded for a host call operation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Number of host calls.
+    pub calls: u64,
+    /// Total size of the encoded host call arguments.
+    pub input_bytes: u64,
+    /// Total size of the encoded host call results.
+    pub output_bytes: u64,
+    /// Total number of arguments, i.e. the number of bases for the MSMs and the
+    /// registrations, the number of scalars for the MSMs with a handle, the number
+    /// of pairs for the Miller loop and the pairing check, the number of elements
+    /// for the FFTs and batch inversions, the number of calls for the batches, the
+    /// number of points for the normalisations and deserializations and one for
+    /// any other operation.
+    pub args: u64,
+}
+
+impl Usage {
+    /// Usage recorded since `earlier` was taken.
+    pub fn since(self, earlier: Usage) -> Usage {
+        Usage {
+            calls: self.calls.saturating_sub(earlier.calls),
+            input_bytes: self.input_bytes.saturating_sub(earlier.input_bytes),
+            output_bytes: self.output_bytes.saturating_sub(earlier.output_bytes),
+            args: self.args.saturating_sub(earlier.args),
+        }
+    }
+}
+
+/// Result of a host call.
+pub trait HostOutput {
+    /// Size of the result in bytes.
+    fn size(&self) -> usize;
+}
+
+impl HostOutput for Vec<u8> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl HostOutput for bool {
+    fn size(&self) -> usize {
+        1
+    }
+}
+
+struct Counters {
+    calls: AtomicU64,
+    input_bytes: AtomicU64,
+    output_bytes: AtomicU64,
+    args: AtomicU64,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Counters {
+            calls: AtomicU64::new(0),
+            input_bytes: AtomicU64::new(0),
+            output_bytes: AtomicU64::new(0),
+            args: AtomicU64::new(0),
+        }
+    }
+}
+
+/// Per operation host call usage counters.
+pub struct Meter {
+    counters: [Counters; OPERATIONS],
+}
+
+impl Meter {
+    /// Meter with no usage recorded.
+    pub const fn new() -> Self {
+        // Only used to repeat the counters in the array below.
+        #[allow(clippy::declare_interior_mutable_const)]
+        const NEW: Counters = Counters::new();
+        Meter {
+            counters: [NEW; OPERATIONS],
+        }
+    }
+
+    /// Perform the host call `call` of `op`, recording its usage.
+    pub fn call<R: HostOutput>(
+        &self,
+        op: Operation,
+        input_bytes: usize,
+        args: u64,
+        call: impl FnOnce() -> Result<R, ()>,
+    ) -> Result<R, ()> {
+        let result = call();
+        let counters = &self.counters[op as usize];
+        counters.calls.fetch_add(1, Ordering::Relaxed);
+        counters
+            .input_bytes
+            .fetch_add(input_bytes as u64, Ordering::Relaxed);
+        counters.output_bytes.fetch_add(
+            result.as_ref().map_or(0, |output| output.size() as u64),
+            Ordering::Relaxed,
+        );
+        counters.args.fetch_add(args, Ordering::Relaxed);
+        result
+    }
+
+    /// Usage recorded for `op`.
+    pub fn usage(&self, op: Operation) -> Usage {
+        let counters = &self.counters[op as usize];
+        Usage {
+            calls: counters.calls.load(Ordering::Relaxed),
+            input_bytes: counters.input_bytes.load(Ordering::Relaxed),
+            output_bytes: counters.output_bytes.load(Ordering::Relaxed),
+            args: counters.args.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reset the usage recorded for every operation.
+    pub fn reset(&self) {
+        for counters in self.counters.iter() {
+            counters.calls.store(0, Ordering::Relaxed);
+            counters.input_bytes.store(0, Ordering::Relaxed);
+            counters.output_bytes.store(0, Ordering::Relaxed);
+            counters.args.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Default for Meter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Meters of the host functions of a curve, one for every host functions type.
+pub struct Meters {
+    meters: Mutex<Vec<(TypeId, &'static Meter)>>,
+}
+
+impl Meters {
+    /// No meter yet.
+    pub const fn new() -> Self {
+        Meters {
+            meters: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Meter of the host functions `H`, with no usage recorded when first requested.
+    pub fn get<H: 'static>(&self) -> &'static Meter {
+        let id = TypeId::of::<H>();
+        let mut meters = self.meters.lock();
+        match meters.iter().find(|(meter_id, _)| *meter_id == id) {
+            Some((_, meter)) => meter,
+            None => {
+                // Leaked once per host functions type, of which there are few.
+                let meter = Box::leak(Box::new(Meter::new()));
+                meters.push((id, meter));
+                meter
+            }
+        }
+    }
+}
+
+impl Default for Meters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Number of elements of an encoded sequence host call argument.
+pub fn sequence_len(encoded: &[u8]) -> u64 {
+    encoded
+        .get(..8)
+        .and_then(|len| len.try_into().ok())
+        .map_or(0, u64::from_le_bytes)
+}
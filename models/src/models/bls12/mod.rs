@@ -1,6 +1,7 @@
 use ark_ec::{
     models::CurveConfig,
     pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    CurveGroup, Group, VariableBaseMSM,
 };
 use ark_ff::{
     fields::{
@@ -11,10 +12,14 @@ use ark_ff::{
     },
     PrimeField,
 };
-use ark_std::marker::PhantomData;
+use ark_serialize::{CanonicalDeserialize, SerializationError};
+use ark_std::{marker::PhantomData, vec::Vec, Zero};
 use derivative::Derivative;
 
-use crate::models::short_weierstrass::SWCurveConfig;
+use crate::{
+    deserialize::DeserializeBatch,
+    models::{short_weierstrass::SWCurveConfig, PairingCheck},
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -50,6 +55,61 @@ pub trait Bls12Config: 'static + Sized {
     ) -> MillerLoopOutput<Bls12<Self>>;
     fn final_exponentiation(f: MillerLoopOutput<Bls12<Self>>)
         -> Option<PairingOutput<Bls12<Self>>>;
+
+    /// Check whether the product of the pairings of `a_vec` and `b_vec` is one.
+    fn multi_pairing_is_one(
+        a_vec: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b_vec: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> bool {
+        Self::final_exponentiation(Self::multi_miller_loop(a_vec, b_vec))
+            == Some(PairingOutput::zero())
+    }
+
+    /// Compute the Miller loop of points given in projective coordinates, normalising
+    /// them all to affine with one call to [`Self::normalize_g1`] and one to
+    /// [`Self::normalize_g2`] before preparing them.
+    fn multi_miller_loop_projective(
+        a: &[G1Projective<Self>],
+        b: &[G2Projective<Self>],
+    ) -> MillerLoopOutput<Bls12<Self>> {
+        Self::multi_miller_loop(Self::normalize_g1(a), Self::normalize_g2(b))
+    }
+
+    /// Normalise `points` of G1 to affine coordinates, with one batched inversion.
+    fn normalize_g1(points: &[G1Projective<Self>]) -> Vec<G1Affine<Self>> {
+        G1Projective::<Self>::normalize_batch(points)
+    }
+
+    /// Normalise `points` of G2 to affine coordinates, with one batched inversion.
+    fn normalize_g2(points: &[G2Projective<Self>]) -> Vec<G2Affine<Self>> {
+        G2Projective::<Self>::normalize_batch(points)
+    }
+
+    /// Deserialize `compressed`, the compressed encoding of a vector of points of G1,
+    /// checking that they are on the curve and in the prime order subgroup.
+    fn deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<Self>>, SerializationError> {
+        Vec::deserialize_compressed(compressed)
+    }
+
+    /// Deserialize `compressed`, the compressed encoding of a vector of points of G2,
+    /// checking that they are on the curve and in the prime order subgroup.
+    fn deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<Self>>, SerializationError> {
+        Vec::deserialize_compressed(compressed)
+    }
+
+    /// Multiply `base` by the scalar `scalar` in the target group.
+    fn gt_mul(base: &PairingOutput<Bls12<Self>>, scalar: &[u64]) -> PairingOutput<Bls12<Self>> {
+        base.mul_bigint(scalar)
+    }
+
+    /// Multi scalar multiplication in the target group, failing with the smaller length
+    /// if `bases` and `scalars` have different lengths.
+    fn gt_msm(
+        bases: &[PairingOutput<Bls12<Self>>],
+        scalars: &[<Self::G1Config as CurveConfig>::ScalarField],
+    ) -> Result<PairingOutput<Bls12<Self>>, usize> {
+        VariableBaseMSM::msm(bases, scalars)
+    }
 }
 
 pub mod g1;
@@ -64,6 +124,61 @@ pub use self::{
 #[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub struct Bls12<P: Bls12Config>(PhantomData<fn() -> P>);
 
+impl<P: Bls12Config> Bls12<P> {
+    /// Compute the Miller loop of points given in projective coordinates, see
+    /// [`Bls12Config::multi_miller_loop_projective`].
+    pub fn multi_miller_loop_projective(
+        a: &[G1Projective<P>],
+        b: &[G2Projective<P>],
+    ) -> MillerLoopOutput<Self> {
+        P::multi_miller_loop_projective(a, b)
+    }
+
+    /// Check whether the product of the pairings of `a` and `b` is one.
+    pub fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<P>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<P>>>,
+    ) -> bool {
+        P::multi_pairing_is_one(a, b)
+    }
+
+    /// Multiply `base` by `scalar` in the target group.
+    pub fn gt_mul(
+        base: &PairingOutput<Self>,
+        scalar: <Self as Pairing>::ScalarField,
+    ) -> PairingOutput<Self> {
+        P::gt_mul(base, scalar.into_bigint().as_ref())
+    }
+
+    /// Multi scalar multiplication in the target group, failing with the smaller length
+    /// if `bases` and `scalars` have different lengths.
+    pub fn gt_msm(
+        bases: &[PairingOutput<Self>],
+        scalars: &[<Self as Pairing>::ScalarField],
+    ) -> Result<PairingOutput<Self>, usize> {
+        P::gt_msm(bases, scalars)
+    }
+}
+
+impl<P: Bls12Config> PairingCheck for Bls12<P> {
+    fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> bool {
+        P::multi_pairing_is_one(a, b)
+    }
+}
+
+impl<P: Bls12Config> DeserializeBatch for Bls12<P> {
+    fn deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<P>>, SerializationError> {
+        P::deserialize_g1_batch(compressed)
+    }
+
+    fn deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<P>>, SerializationError> {
+        P::deserialize_g2_batch(compressed)
+    }
+}
+
 impl<P: Bls12Config> Pairing for Bls12<P> {
     type BaseField = <P::G1Config as CurveConfig>::BaseField;
     type ScalarField = <P::G1Config as CurveConfig>::ScalarField;
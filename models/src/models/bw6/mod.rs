@@ -1,15 +1,18 @@
 use crate::{
-    models::{short_weierstrass::SWCurveConfig, CurveConfig},
+    deserialize::DeserializeBatch,
+    models::{short_weierstrass::SWCurveConfig, CurveConfig, PairingCheck},
     pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    CurveGroup, Group, VariableBaseMSM,
 };
 use ark_ff::fields::{
     fp3::Fp3Config,
     fp6_2over3::{Fp6, Fp6Config},
     PrimeField,
 };
+use ark_serialize::{CanonicalDeserialize, SerializationError};
 use derivative::Derivative;
 
-use ark_std::marker::PhantomData;
+use ark_std::{marker::PhantomData, vec::Vec, Zero};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -42,6 +45,60 @@ pub trait BW6Config: 'static + Sized {
         a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
         b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
     ) -> MillerLoopOutput<BW6<Self>>;
+
+    /// Check whether the product of the pairings of `a` and `b` is one.
+    fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<Self>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<Self>>>,
+    ) -> bool {
+        Self::final_exponentiation(Self::multi_miller_loop(a, b)) == Some(PairingOutput::zero())
+    }
+
+    /// Compute the Miller loop of points given in projective coordinates, normalising
+    /// them all to affine with one call to [`Self::normalize_g1`] and one to
+    /// [`Self::normalize_g2`] before preparing them.
+    fn multi_miller_loop_projective(
+        a: &[G1Projective<Self>],
+        b: &[G2Projective<Self>],
+    ) -> MillerLoopOutput<BW6<Self>> {
+        Self::multi_miller_loop(Self::normalize_g1(a), Self::normalize_g2(b))
+    }
+
+    /// Normalise `points` of G1 to affine coordinates, with one batched inversion.
+    fn normalize_g1(points: &[G1Projective<Self>]) -> Vec<G1Affine<Self>> {
+        G1Projective::<Self>::normalize_batch(points)
+    }
+
+    /// Normalise `points` of G2 to affine coordinates, with one batched inversion.
+    fn normalize_g2(points: &[G2Projective<Self>]) -> Vec<G2Affine<Self>> {
+        G2Projective::<Self>::normalize_batch(points)
+    }
+
+    /// Deserialize `compressed`, the compressed encoding of a vector of points of G1,
+    /// checking that they are on the curve and in the prime order subgroup.
+    fn deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<Self>>, SerializationError> {
+        Vec::deserialize_compressed(compressed)
+    }
+
+    /// Deserialize `compressed`, the compressed encoding of a vector of points of G2,
+    /// checking that they are on the curve and in the prime order subgroup.
+    fn deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<Self>>, SerializationError> {
+        Vec::deserialize_compressed(compressed)
+    }
+
+    /// Multiply `base` by the scalar `scalar` in the target group.
+    fn gt_mul(base: &PairingOutput<BW6<Self>>, scalar: &[u64]) -> PairingOutput<BW6<Self>> {
+        base.mul_bigint(scalar)
+    }
+
+    /// Multi scalar multiplication in the target group, failing with the smaller length
+    /// if `bases` and `scalars` have different lengths.
+    fn gt_msm(
+        bases: &[PairingOutput<BW6<Self>>],
+        scalars: &[<Self::G1Config as CurveConfig>::ScalarField],
+    ) -> Result<PairingOutput<BW6<Self>>, usize> {
+        VariableBaseMSM::msm(bases, scalars)
+    }
 }
 
 pub mod g1;
@@ -56,6 +113,61 @@ pub use self::{
 #[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub struct BW6<P: BW6Config>(PhantomData<fn() -> P>);
 
+impl<P: BW6Config> BW6<P> {
+    /// Compute the Miller loop of points given in projective coordinates, see
+    /// [`BW6Config::multi_miller_loop_projective`].
+    pub fn multi_miller_loop_projective(
+        a: &[G1Projective<P>],
+        b: &[G2Projective<P>],
+    ) -> MillerLoopOutput<Self> {
+        P::multi_miller_loop_projective(a, b)
+    }
+
+    /// Check whether the product of the pairings of `a` and `b` is one.
+    pub fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<G1Prepared<P>>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared<P>>>,
+    ) -> bool {
+        P::multi_pairing_is_one(a, b)
+    }
+
+    /// Multiply `base` by `scalar` in the target group.
+    pub fn gt_mul(
+        base: &PairingOutput<Self>,
+        scalar: <Self as Pairing>::ScalarField,
+    ) -> PairingOutput<Self> {
+        P::gt_mul(base, scalar.into_bigint().as_ref())
+    }
+
+    /// Multi scalar multiplication in the target group, failing with the smaller length
+    /// if `bases` and `scalars` have different lengths.
+    pub fn gt_msm(
+        bases: &[PairingOutput<Self>],
+        scalars: &[<Self as Pairing>::ScalarField],
+    ) -> Result<PairingOutput<Self>, usize> {
+        P::gt_msm(bases, scalars)
+    }
+}
+
+impl<P: BW6Config> PairingCheck for BW6<P> {
+    fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> bool {
+        P::multi_pairing_is_one(a, b)
+    }
+}
+
+impl<P: BW6Config> DeserializeBatch for BW6<P> {
+    fn deserialize_g1_batch(compressed: &[u8]) -> Result<Vec<G1Affine<P>>, SerializationError> {
+        P::deserialize_g1_batch(compressed)
+    }
+
+    fn deserialize_g2_batch(compressed: &[u8]) -> Result<Vec<G2Affine<P>>, SerializationError> {
+        P::deserialize_g2_batch(compressed)
+    }
+}
+
 impl<P: BW6Config> Pairing for BW6<P> {
     type BaseField = <P::G1Config as CurveConfig>::BaseField;
     type ScalarField = <P::G1Config as CurveConfig>::ScalarField;
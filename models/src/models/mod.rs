@@ -1,3 +1,14 @@
 pub mod bls12;
 pub mod bw6;
 pub use ark_ec::{pairing, short_weierstrass, twisted_edwards, CurveConfig};
+
+use ark_ec::pairing::Pairing;
+
+/// Pairing engines checking a product of pairings in a single step.
+pub trait PairingCheck: Pairing {
+    /// Check whether the product of the pairings of `a` and `b` is one.
+    fn multi_pairing_is_one(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> bool;
+}
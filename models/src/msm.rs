@@ -0,0 +1,56 @@
+//! Fallible multi scalar multiplications.
+//!
+//! [`VariableBaseMSM::msm`] can only fail with a length mismatch, so the curve
+//! configs fall back to software when their host MSM fails. Generic code which
+//! should rather surface host call errors, such as verifiers returning a dispatch
+//! error, bounds its groups by [`TryMsm`], which the projective points of every
+//! curve config implementing [`SWTryMsm`] or [`TETryMsm`] implement.
+
+use ark_ec::{
+    short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig},
+    twisted_edwards::{Affine as TEAffine, Projective as TEProjective, TECurveConfig},
+    CurveConfig, VariableBaseMSM,
+};
+
+use crate::HostCallError;
+
+/// Groups computing their multi scalar multiplications with a fallible host call.
+pub trait TryMsm: VariableBaseMSM {
+    /// Multi scalar multiplication of `bases` by `scalars`, surfacing host call errors.
+    fn try_msm(
+        bases: &[Self::MulBase],
+        scalars: &[Self::ScalarField],
+    ) -> Result<Self, HostCallError>;
+}
+
+/// Short Weierstrass curve configs computing their multi scalar multiplications with
+/// a fallible host call.
+pub trait SWTryMsm: SWCurveConfig {
+    /// Multi scalar multiplication of `bases` by `scalars`, surfacing host call errors.
+    fn try_msm(
+        bases: &[SWAffine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<SWProjective<Self>, HostCallError>;
+}
+
+/// Twisted Edwards curve configs computing their multi scalar multiplications with
+/// a fallible host call.
+pub trait TETryMsm: TECurveConfig {
+    /// Multi scalar multiplication of `bases` by `scalars`, surfacing host call errors.
+    fn try_msm(
+        bases: &[TEAffine<Self>],
+        scalars: &[<Self as CurveConfig>::ScalarField],
+    ) -> Result<TEProjective<Self>, HostCallError>;
+}
+
+impl<C: SWTryMsm> TryMsm for SWProjective<C> {
+    fn try_msm(bases: &[SWAffine<C>], scalars: &[C::ScalarField]) -> Result<Self, HostCallError> {
+        <C as SWTryMsm>::try_msm(bases, scalars)
+    }
+}
+
+impl<C: TETryMsm> TryMsm for TEProjective<C> {
+    fn try_msm(bases: &[TEAffine<C>], scalars: &[C::ScalarField]) -> Result<Self, HostCallError> {
+        <C as TETryMsm>::try_msm(bases, scalars)
+    }
+}
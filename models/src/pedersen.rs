@@ -0,0 +1,133 @@
+//! Pedersen vector commitments.
+//!
+//! A commitment to the values `vᵢ` with the blinding factor `r` is `Σ vᵢ·Gᵢ + r·H`,
+//! computed with a single multi scalar multiplication, performed by the host for the
+//! host accelerated curves. The generators are derived from a domain separator, so
+//! that nobody knows the discrete logarithms between them. Commitments are additively
+//! homomorphic: the sum of the commitments to `a` and `b` with the blinding factors
+//! `r` and `s` is the commitment to `a + b` with the blinding factor `r + s`.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_std::{
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    vec::Vec,
+};
+use sha2::{Digest, Sha512};
+
+use crate::{HostCallError, TryMsm};
+
+/// Generators of the commitments to vectors of up to `bases.len()` values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generators<G: CurveGroup> {
+    /// Bases of the committed values.
+    pub bases: Vec<G::Affine>,
+    /// Base of the blinding factor.
+    pub blinding: G::Affine,
+}
+
+impl<G: CurveGroup> Generators<G> {
+    /// Derive the generators of the commitments to vectors of up to `len` values
+    /// from the domain separator `domain`.
+    ///
+    /// The generators of a shorter vector are a prefix of the generators of a
+    /// longer one with the same domain separator.
+    pub fn new(domain: &[u8], len: usize) -> Self {
+        Generators {
+            bases: (0..len as u64)
+                .map(|i| hash_to_curve::<G>(domain, b"base", &i.to_le_bytes()))
+                .collect(),
+            blinding: hash_to_curve::<G>(domain, b"blinding", &[]),
+        }
+    }
+}
+
+/// Pedersen commitment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment<G: CurveGroup>(pub G::Affine);
+
+impl<G: CurveGroup> Add for Commitment<G> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Commitment((self.0 + other.0).into_affine())
+    }
+}
+
+impl<G: CurveGroup> AddAssign for Commitment<G> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<G: CurveGroup> Sub for Commitment<G> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Commitment((self.0.into_group() - other.0).into_affine())
+    }
+}
+
+impl<G: CurveGroup> SubAssign for Commitment<G> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<G: CurveGroup> Neg for Commitment<G> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Commitment((-self.0.into_group()).into_affine())
+    }
+}
+
+/// Commit to `values` with the blinding factor `blinding`.
+pub fn commit<G: CurveGroup + TryMsm>(
+    generators: &Generators<G>,
+    values: &[G::ScalarField],
+    blinding: G::ScalarField,
+) -> Result<Commitment<G>, HostCallError> {
+    if values.len() > generators.bases.len() {
+        return Err(HostCallError::LengthMismatch);
+    }
+    let mut bases = Vec::with_capacity(values.len() + 1);
+    bases.extend_from_slice(&generators.bases[..values.len()]);
+    bases.push(generators.blinding);
+    let mut scalars = Vec::with_capacity(values.len() + 1);
+    scalars.extend_from_slice(values);
+    scalars.push(blinding);
+    let commitment = G::try_msm(&bases, &scalars)?;
+    Ok(Commitment(commitment.into_affine()))
+}
+
+/// Check that `commitment` opens to `values` with the blinding factor `blinding`.
+pub fn verify_opening<G: CurveGroup + TryMsm>(
+    generators: &Generators<G>,
+    commitment: &Commitment<G>,
+    values: &[G::ScalarField],
+    blinding: G::ScalarField,
+) -> Result<bool, HostCallError> {
+    Ok(commit(generators, values, blinding)? == *commitment)
+}
+
+/// Try and increment hashing of `label` and `index` to the prime order subgroup,
+/// domain separated by `domain`.
+fn hash_to_curve<G: CurveGroup>(domain: &[u8], label: &[u8], index: &[u8]) -> G::Affine {
+    for ctr in 0..=u8::MAX {
+        let hash = Sha512::new()
+            .chain_update((domain.len() as u64).to_le_bytes())
+            .chain_update(domain)
+            .chain_update(label)
+            .chain_update(index)
+            .chain_update([ctr])
+            .finalize();
+        if let Some(point) = G::Affine::from_random_bytes(&hash) {
+            let point = point.clear_cofactor();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+    }
+    // Every candidate failing has probability 2⁻²⁵⁶.
+    unreachable!("no valid point in 256 tries")
+}
@@ -0,0 +1,91 @@
+//! Bases registered with the host.
+//!
+//! Multi scalar multiplications over a fixed set of bases, such as a KZG SRS or
+//! Pedersen generators, otherwise encode and send every base to the host on each
+//! call. Hosts supporting it instead keep a set of bases registered once under a
+//! [`Handle`], the SHA-256 hash of their encoding, after which only the scalars
+//! cross the host boundary.
+//!
+//! [`RegisteredBases`] keeps the bases alongside their handle, computing its MSMs
+//! with the plain host MSM when the host does not know the handle, for instance
+//! because it does not support registration or dropped its registry.
+
+use ark_ec::CurveGroup;
+use ark_std::{marker::PhantomData, vec::Vec};
+use derivative::Derivative;
+use sha2::{Digest, Sha256};
+
+use crate::{HostCallError, TryMsm};
+
+/// Handle of a set of registered bases.
+pub type Handle = [u8; 32];
+
+/// Handle of the bases with the host call encoding `encoded_bases`.
+pub fn handle(encoded_bases: &[u8]) -> Handle {
+    Sha256::digest(encoded_bases).into()
+}
+
+/// Host functions keeping registered bases of `G`.
+pub trait BasesRegistry<G: CurveGroup>: 'static {
+    /// Register `bases` with the host, returning their handle.
+    fn register_bases(bases: &[G::Affine]) -> Result<Handle, HostCallError>;
+
+    /// Multi scalar multiplication of the first `scalars.len()` bases registered
+    /// under `handle` by `scalars`.
+    fn msm_with_handle(handle: &Handle, scalars: &[G::ScalarField]) -> Result<G, HostCallError>;
+}
+
+/// Bases of `G` registered with the host `R`.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = ""),
+    Debug(bound = "")
+)]
+pub struct RegisteredBases<G: CurveGroup, R: BasesRegistry<G>> {
+    bases: Vec<G::Affine>,
+    handle: Option<Handle>,
+    _registry: PhantomData<fn() -> R>,
+}
+
+impl<G: CurveGroup, R: BasesRegistry<G>> RegisteredBases<G, R> {
+    /// Register `bases` with the host.
+    ///
+    /// The bases are kept without a handle if the host fails to register them.
+    pub fn new(bases: Vec<G::Affine>) -> Self {
+        let handle = R::register_bases(&bases).ok();
+        RegisteredBases {
+            bases,
+            handle,
+            _registry: PhantomData,
+        }
+    }
+
+    /// Registered bases.
+    pub fn bases(&self) -> &[G::Affine] {
+        &self.bases
+    }
+
+    /// Handle of the bases, if the host registered them.
+    pub fn handle(&self) -> Option<&Handle> {
+        self.handle.as_ref()
+    }
+
+    /// Multi scalar multiplication of the first `scalars.len()` bases by `scalars`,
+    /// with the registered bases if possible and a plain MSM otherwise.
+    pub fn msm(&self, scalars: &[G::ScalarField]) -> Result<G, HostCallError>
+    where
+        G: TryMsm,
+    {
+        if scalars.len() > self.bases.len() {
+            return Err(HostCallError::LengthMismatch);
+        }
+        if let Some(handle) = &self.handle {
+            if let Ok(result) = R::msm_with_handle(handle, scalars) {
+                return Ok(result);
+            }
+        }
+        G::try_msm(&self.bases[..scalars.len()], scalars)
+    }
+}
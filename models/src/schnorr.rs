@@ -0,0 +1,186 @@
+//! Schnorr signatures.
+//!
+//! A signature of `msg` by the key `PK = sk·G` is `(R, s)` with `R = k·G`,
+//! `s = k + c·sk` and the challenge `c = D(R || PK || msg)`, for a hash function `D`
+//! chosen by the caller. The nonce `k` is derived deterministically from the secret
+//! key and the message with SHA-512. Points are encoded compressed, as by
+//! `ark-serialize`.
+//!
+//! Verification rejects public keys in the torsion subgroup, which would verify any
+//! signature, and checks `[h](s·G - R - c·PK) = 0`, with `h` the cofactor, with a
+//! single multi scalar multiplication performed by the host for the host accelerated
+//! curves, as does batch verification for any number of signatures.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::{rand::RngCore, vec::Vec, UniformRand};
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{HostCallError, TryMsm};
+
+/// Schnorr secret key, zeroized when dropped.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct SecretKey<G: CurveGroup>(G::ScalarField);
+
+impl<G: CurveGroup> SecretKey<G> {
+    /// Derive a secret key from `seed`.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        SecretKey(G::ScalarField::from_le_bytes_mod_order(&Sha512::digest(
+            seed,
+        )))
+    }
+
+    /// Secret key with the scalar `scalar`, `None` if it is zero.
+    pub fn from_scalar(scalar: G::ScalarField) -> Option<Self> {
+        (!scalar.is_zero()).then(|| SecretKey(scalar))
+    }
+
+    /// Public key of the secret key.
+    pub fn public(&self) -> PublicKey<G> {
+        PublicKey((G::generator() * self.0).into_affine())
+    }
+}
+
+/// Schnorr public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey<G: CurveGroup>(pub G::Affine);
+
+/// Schnorr signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<G: CurveGroup> {
+    pub r: G::Affine,
+    pub s: G::ScalarField,
+}
+
+/// Sign `msg` with `sk`, the challenge being hashed with `D`.
+pub fn sign<G: CurveGroup, D: Digest>(sk: &SecretKey<G>, msg: &[u8]) -> Signature<G> {
+    let pk = sk.public();
+    let mut hasher = Sha512::new();
+    absorb(&mut hasher, &sk.0);
+    absorb(&mut hasher, &pk.0);
+    hasher.update(msg);
+    let k = G::ScalarField::from_le_bytes_mod_order(&hasher.finalize());
+
+    let r = (G::generator() * k).into_affine();
+    let c = challenge::<G, D>(&r, &pk, msg);
+    Signature { r, s: k + c * sk.0 }
+}
+
+/// Verify the signature `signature` of `msg` by `pk`, the challenge being hashed
+/// with `D`.
+pub fn verify<G: CurveGroup + TryMsm, D: Digest>(
+    pk: &PublicKey<G>,
+    msg: &[u8],
+    signature: &Signature<G>,
+) -> Result<bool, HostCallError> {
+    if is_torsion(pk.0.into_group()) {
+        return Ok(false);
+    }
+    let c = challenge::<G, D>(&signature.r, pk, msg);
+    // s·G - R - c·PK
+    let check = G::try_msm(
+        &[G::generator().into_affine(), signature.r, pk.0],
+        &[signature.s, -G::ScalarField::from(1u8), -c],
+    )?;
+    Ok(is_torsion(check))
+}
+
+/// Verify the signatures `signatures` of `messages` by `public_keys` at once,
+/// combining them with random scalars drawn from `rng`, the challenges being hashed
+/// with `D`.
+pub fn batch_verify<G: CurveGroup + TryMsm, D: Digest, R: RngCore>(
+    public_keys: &[PublicKey<G>],
+    messages: &[&[u8]],
+    signatures: &[Signature<G>],
+    rng: &mut R,
+) -> Result<bool, HostCallError> {
+    if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+        return Err(HostCallError::LengthMismatch);
+    }
+    if public_keys.iter().any(|pk| is_torsion(pk.0.into_group())) {
+        return Ok(false);
+    }
+    // Σ zᵢ (sᵢ·G - Rᵢ - cᵢ·PKᵢ)
+    let mut bases = Vec::with_capacity(2 * signatures.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * signatures.len() + 1);
+    let mut s = G::ScalarField::zero();
+    for ((pk, msg), signature) in public_keys.iter().zip(messages).zip(signatures) {
+        let z = G::ScalarField::rand(rng);
+        let c = challenge::<G, D>(&signature.r, pk, msg);
+        s += z * signature.s;
+        bases.push(signature.r);
+        scalars.push(-z);
+        bases.push(pk.0);
+        scalars.push(-z * c);
+    }
+    bases.push(G::generator().into_affine());
+    scalars.push(s);
+    let check = G::try_msm(&bases, &scalars)?;
+    Ok(is_torsion(check))
+}
+
+/// Whether `point` is in the torsion subgroup, i.e. vanishes once multiplied by the
+/// cofactor.
+fn is_torsion<G: CurveGroup>(point: G) -> bool {
+    point.into_affine().mul_by_cofactor_to_group().is_zero()
+}
+
+fn challenge<G: CurveGroup, D: Digest>(
+    r: &G::Affine,
+    pk: &PublicKey<G>,
+    msg: &[u8],
+) -> G::ScalarField {
+    let mut hasher = D::new();
+    absorb(&mut hasher, r);
+    absorb(&mut hasher, &pk.0);
+    hasher.update(msg);
+    G::ScalarField::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+fn absorb<D: Digest, T: CanonicalSerialize>(hasher: &mut D, value: &T) {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .expect("serializing to a vector never fails");
+    hasher.update(bytes);
+}
+
+impl<G: CurveGroup> Valid for Signature<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.r.check()?;
+        self.s.check()
+    }
+}
+
+impl<G: CurveGroup> CanonicalSerialize for Signature<G> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.r.serialize_with_mode(&mut writer, compress)?;
+        self.s.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.r.serialized_size(compress) + self.s.serialized_size(compress)
+    }
+}
+
+impl<G: CurveGroup> CanonicalDeserialize for Signature<G> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Signature {
+            r: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            s: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
@@ -0,0 +1,103 @@
+//! Multi scalar multiplications by small scalars.
+//!
+//! Batch verification combines its items with random 64 or 128-bit coefficients,
+//! for which full width scalar field elements waste both the encoding and the host
+//! work. The `*_msm_small` host functions instead take the scalars as little-endian
+//! `u64` limbs, the same number for every scalar, and run a Pippenger over the bits
+//! actually used.
+//!
+//! Curve configs implement [`SmallMsm`] over the affine points of their groups,
+//! whose [`SmallMsm::msm_u64`] and [`SmallMsm::msm_u128`] fall back to the plain
+//! host MSM when the host does not provide the small scalar one.
+
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_std::{vec, vec::Vec};
+
+use crate::HostCallError;
+
+/// Host functions computing multi scalar multiplications of `A` by small scalars.
+pub trait SmallMsm<A: AffineRepr>: 'static {
+    /// Multi scalar multiplication of `bases` by the scalars with the little-endian
+    /// limbs `limbs`, `limbs.len() / bases.len()` of them per scalar.
+    fn try_msm_small(bases: &[A], limbs: &[u64]) -> Result<A::Group, HostCallError>;
+
+    /// Multi scalar multiplication of `bases` by 64-bit `scalars`.
+    fn msm_u64(bases: &[A], scalars: &[u64]) -> Result<A::Group, usize> {
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        Self::try_msm_small(bases, scalars).or_else(|_| {
+            let scalars = scalars
+                .iter()
+                .map(|s| A::ScalarField::from(*s))
+                .collect::<Vec<_>>();
+            A::Group::msm(bases, &scalars)
+        })
+    }
+
+    /// Multi scalar multiplication of `bases` by 128-bit `scalars`.
+    fn msm_u128(bases: &[A], scalars: &[u128]) -> Result<A::Group, usize> {
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
+        }
+        let limbs = scalars
+            .iter()
+            .flat_map(|s| [*s as u64, (*s >> 64) as u64])
+            .collect::<Vec<_>>();
+        Self::try_msm_small(bases, &limbs).or_else(|_| {
+            let scalars = scalars
+                .iter()
+                .map(|s| A::ScalarField::from(*s))
+                .collect::<Vec<_>>();
+            A::Group::msm(bases, &scalars)
+        })
+    }
+}
+
+/// Scalars with the little-endian limbs `limbs`, for `len` scalars of one or two
+/// limbs each.
+pub fn from_limbs(limbs: &[u64], len: usize) -> Option<Vec<u128>> {
+    match (len, limbs.len().checked_div(len)) {
+        (0, _) if limbs.is_empty() => Some(Vec::new()),
+        (_, Some(1)) if limbs.len() == len => Some(limbs.iter().map(|l| *l as u128).collect()),
+        (_, Some(2)) if limbs.len() == 2 * len => Some(
+            limbs
+                .chunks(2)
+                .map(|l| l[0] as u128 | (l[1] as u128) << 64)
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Multi scalar multiplication of `bases` by `scalars`, with a Pippenger over the
+/// bits of the largest scalar only.
+pub fn msm<G: CurveGroup>(bases: &[G::Affine], scalars: &[u128]) -> G {
+    let bits = 128 - scalars.iter().fold(0, |acc, s| acc | s).leading_zeros() as usize;
+    let window = match bases.len() {
+        0..=31 => 3,
+        // About ln(n) + 2, as in arkworks.
+        n => (usize::BITS - n.leading_zeros()) as usize * 69 / 100 + 2,
+    };
+
+    let mut result = G::zero();
+    for start in (0..bits).step_by(window).rev() {
+        for _ in 0..window {
+            result.double_in_place();
+        }
+        let mut buckets = vec![G::zero(); (1 << window) - 1];
+        for (base, scalar) in bases.iter().zip(scalars) {
+            let digit = (scalar >> start) as usize & ((1 << window) - 1);
+            if digit != 0 {
+                buckets[digit - 1] += base;
+            }
+        }
+        // Σ i·bucketᵢ as the sum of the running sums from the top bucket down.
+        let mut running = G::zero();
+        for bucket in buckets.into_iter().rev() {
+            running += bucket;
+            result += running;
+        }
+    }
+    result
+}
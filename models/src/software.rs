@@ -0,0 +1,324 @@
+//! Software implementations of the elliptic curve host calls.
+//!
+//! Every function decodes its `ArkScale` host call arguments, computes the
+//! result with the given arkworks curve and re-encodes it, mirroring the
+//! elliptic curve host functions. [`double_and_add`] is the scalar multiplication
+//! that the curves fall back to when these host calls fail.
+
+#![allow(clippy::result_unit_err)]
+
+use ark_ec::{
+    hashing::{
+        curve_maps::wb::{WBConfig, WBMap},
+        map_to_curve_hasher::MapToCurveBasedHasher,
+        HashToCurve,
+    },
+    pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig},
+    twisted_edwards::{Affine as TEAffine, Projective as TEProjective, TECurveConfig},
+    AffineRepr, CurveConfig, CurveGroup, Group, VariableBaseMSM,
+};
+use ark_ff::{batch_inversion, field_hashers::DefaultFieldHasher, BitIteratorBE, FftField, Field};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_scale::hazmat::ArkScaleProjective;
+use ark_serialize::CanonicalDeserialize;
+use ark_std::{ops::AddAssign, vec::Vec, Zero};
+use codec::{Decode, Encode};
+use sha2::Sha256;
+
+use crate::{
+    registry::{self, Handle},
+    small_msm,
+    torus::{Compressed, TorusCompression},
+};
+
+const HOST_CALL: ark_scale::Usage = ark_scale::HOST_CALL;
+type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
+
+fn decode<T: Decode>(mut bytes: &[u8]) -> Result<T, ()> {
+    T::decode(&mut bytes).map_err(|_| ())
+}
+
+/// Compute a multi Miller loop for `Curve`.
+pub fn multi_miller_loop<Curve: Pairing>(a: &[u8], b: &[u8]) -> Result<Vec<u8>, ()> {
+    let a = decode::<ArkScale<Vec<<Curve as Pairing>::G1Affine>>>(a)?;
+    let b = decode::<ArkScale<Vec<<Curve as Pairing>::G2Affine>>>(b)?;
+
+    let result = Curve::multi_miller_loop(a.0, b.0).0;
+
+    let result: ArkScale<<Curve as Pairing>::TargetField> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a final exponentiation for `Curve`.
+///
+/// The result is torus compressed, see [`crate::torus`].
+pub fn final_exponentiation<Curve: Pairing>(target: &[u8]) -> Result<Vec<u8>, ()>
+where
+    Curve::TargetField: TorusCompression,
+{
+    let target = decode::<ArkScale<<Curve as Pairing>::TargetField>>(target)?;
+
+    let result = Curve::final_exponentiation(MillerLoopOutput(target.0)).ok_or(())?;
+
+    let result: ArkScale<Compressed<Curve>> = Compressed(result).into();
+    Ok(result.encode())
+}
+
+/// Check whether the product of the pairings for `Curve` is one.
+pub fn pairing_check<Curve: Pairing>(a: &[u8], b: &[u8]) -> Result<bool, ()> {
+    let a = decode::<ArkScale<Vec<<Curve as Pairing>::G1Affine>>>(a)?;
+    let b = decode::<ArkScale<Vec<<Curve as Pairing>::G2Affine>>>(b)?;
+
+    Ok(Curve::multi_pairing(a.0, b.0).is_zero())
+}
+
+/// Compute a multi scalar multiplication on a short Weierstrass curve.
+pub fn msm_sw<Curve: SWCurveConfig>(bases: &[u8], scalars: &[u8]) -> Result<Vec<u8>, ()> {
+    let bases = decode::<ArkScale<Vec<SWAffine<Curve>>>>(bases)?;
+    let scalars = decode::<ArkScale<Vec<<Curve as CurveConfig>::ScalarField>>>(scalars)?;
+
+    let result =
+        <SWProjective<Curve> as VariableBaseMSM>::msm(&bases.0, &scalars.0).map_err(|_| ())?;
+
+    let result: ArkScaleProjective<SWProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a multi scalar multiplication on a twisted Edwards curve.
+pub fn msm_te<Curve: TECurveConfig>(bases: &[u8], scalars: &[u8]) -> Result<Vec<u8>, ()> {
+    let bases = decode::<ArkScale<Vec<TEAffine<Curve>>>>(bases)?;
+    let scalars = decode::<ArkScale<Vec<<Curve as CurveConfig>::ScalarField>>>(scalars)?;
+
+    let result =
+        <TEProjective<Curve> as VariableBaseMSM>::msm(&bases.0, &scalars.0).map_err(|_| ())?;
+
+    let result: ArkScaleProjective<TEProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a multi scalar multiplication by small scalars on a short Weierstrass
+/// curve, see [`crate::small_msm`].
+pub fn msm_small_sw<Curve: SWCurveConfig>(bases: &[u8], limbs: &[u8]) -> Result<Vec<u8>, ()> {
+    let bases = decode::<ArkScale<Vec<SWAffine<Curve>>>>(bases)?;
+    let limbs = decode::<ArkScale<Vec<u64>>>(limbs)?;
+    let scalars = small_msm::from_limbs(&limbs.0, bases.0.len()).ok_or(())?;
+
+    let result = small_msm::msm::<SWProjective<Curve>>(&bases.0, &scalars);
+
+    let result: ArkScaleProjective<SWProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a multi scalar multiplication by small scalars on a twisted Edwards
+/// curve, see [`crate::small_msm`].
+pub fn msm_small_te<Curve: TECurveConfig>(bases: &[u8], limbs: &[u8]) -> Result<Vec<u8>, ()> {
+    let bases = decode::<ArkScale<Vec<TEAffine<Curve>>>>(bases)?;
+    let limbs = decode::<ArkScale<Vec<u64>>>(limbs)?;
+    let scalars = small_msm::from_limbs(&limbs.0, bases.0.len()).ok_or(())?;
+
+    let result = small_msm::msm::<TEProjective<Curve>>(&bases.0, &scalars);
+
+    let result: ArkScaleProjective<TEProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Deserialize the compressed encoding of a vector of points, checking that they
+/// are on the curve and in the prime order subgroup.
+pub fn deserialize_batch<A: AffineRepr>(compressed: &[u8]) -> Result<Vec<u8>, ()> {
+    let points = Vec::<A>::deserialize_compressed(compressed).map_err(|_| ())?;
+
+    let result: ArkScale<Vec<A>> = points.into();
+    Ok(result.encode())
+}
+
+/// Normalise projective points of a short Weierstrass curve to affine, with one
+/// batched inversion.
+///
+/// The points are encoded as their number, a little-endian `u64` like the length
+/// of the other sequences, followed by their projective coordinates.
+pub fn normalize_sw<Curve: SWCurveConfig>(points: &[u8]) -> Result<Vec<u8>, ()> {
+    let mut input = points;
+    let len = u64::decode(&mut input).map_err(|_| ())?;
+    let points = (0..len)
+        .map(|_| ArkScaleProjective::<SWProjective<Curve>>::decode(&mut input).map(|p| p.0))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ())?;
+
+    let result = SWProjective::normalize_batch(&points);
+
+    let result: ArkScale<Vec<SWAffine<Curve>>> = result.into();
+    Ok(result.encode())
+}
+
+/// Decode a sequence of bases to register, returning them with their handle, see
+/// [`crate::registry`].
+pub fn register_bases<A: AffineRepr>(bases: &[u8]) -> Result<(Handle, Vec<A>), ()> {
+    let handle = registry::handle(bases);
+    let bases = decode::<ArkScale<Vec<A>>>(bases)?;
+
+    Ok((handle, bases.0))
+}
+
+/// Compute a multi scalar multiplication of the first registered `bases` on a short
+/// Weierstrass curve.
+pub fn msm_with_bases_sw<Curve: SWCurveConfig>(
+    bases: &[SWAffine<Curve>],
+    scalars: &[u8],
+) -> Result<Vec<u8>, ()> {
+    let scalars = decode::<ArkScale<Vec<<Curve as CurveConfig>::ScalarField>>>(scalars)?;
+    let bases = bases.get(..scalars.0.len()).ok_or(())?;
+
+    let result =
+        <SWProjective<Curve> as VariableBaseMSM>::msm(bases, &scalars.0).map_err(|_| ())?;
+
+    let result: ArkScaleProjective<SWProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a multi scalar multiplication of the first registered `bases` on a
+/// twisted Edwards curve.
+pub fn msm_with_bases_te<Curve: TECurveConfig>(
+    bases: &[TEAffine<Curve>],
+    scalars: &[u8],
+) -> Result<Vec<u8>, ()> {
+    let scalars = decode::<ArkScale<Vec<<Curve as CurveConfig>::ScalarField>>>(scalars)?;
+    let bases = bases.get(..scalars.0.len()).ok_or(())?;
+
+    let result =
+        <TEProjective<Curve> as VariableBaseMSM>::msm(bases, &scalars.0).map_err(|_| ())?;
+
+    let result: ArkScaleProjective<TEProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a projective scalar multiplication on a short Weierstrass curve.
+pub fn mul_projective_sw<Curve: SWCurveConfig>(base: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ()> {
+    let base = decode::<ArkScaleProjective<SWProjective<Curve>>>(base)?;
+    let scalar = decode::<ArkScale<Vec<u64>>>(scalar)?;
+
+    let result = <Curve as SWCurveConfig>::mul_projective(&base.0, &scalar.0);
+
+    let result: ArkScaleProjective<SWProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a projective scalar multiplication on a twisted Edwards curve.
+pub fn mul_projective_te<Curve: TECurveConfig>(base: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ()> {
+    let base = decode::<ArkScaleProjective<TEProjective<Curve>>>(base)?;
+    let scalar = decode::<ArkScale<Vec<u64>>>(scalar)?;
+
+    let result = <Curve as TECurveConfig>::mul_projective(&base.0, &scalar.0);
+
+    let result: ArkScaleProjective<TEProjective<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Multiply `base` by `scalar` with the double and add of the default arkworks
+/// `mul_projective` and `mul_affine`, without any host call.
+pub fn double_and_add<G, B>(base: &B, scalar: &[u64]) -> G
+where
+    for<'a> G: Group + AddAssign<&'a B>,
+{
+    let mut result = G::zero();
+    for bit in BitIteratorBE::without_leading_zeros(scalar) {
+        result.double_in_place();
+        if bit {
+            result += base;
+        }
+    }
+    result
+}
+
+/// Compute a scalar multiplication in the target group of `Curve`.
+pub fn mul_gt<Curve: Pairing>(base: &[u8], scalar: &[u8]) -> Result<Vec<u8>, ()> {
+    let base = decode::<ArkScale<PairingOutput<Curve>>>(base)?;
+    let scalar = decode::<ArkScale<Vec<u64>>>(scalar)?;
+
+    let result = base.0.mul_bigint(&scalar.0);
+
+    let result: ArkScale<PairingOutput<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute a multi scalar multiplication in the target group of `Curve`.
+pub fn msm_gt<Curve: Pairing>(bases: &[u8], scalars: &[u8]) -> Result<Vec<u8>, ()> {
+    let bases = decode::<ArkScale<Vec<PairingOutput<Curve>>>>(bases)?;
+    let scalars = decode::<ArkScale<Vec<<Curve as Pairing>::ScalarField>>>(scalars)?;
+
+    let result =
+        <PairingOutput<Curve> as VariableBaseMSM>::msm(&bases.0, &scalars.0).map_err(|_| ())?;
+
+    let result: ArkScale<PairingOutput<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Compute the evaluations of a polynomial over a coset of a radix-2 domain of `F`.
+pub fn fft<F: FftField>(coeffs: &[u8], offset: &[u8]) -> Result<Vec<u8>, ()> {
+    let coeffs = decode::<ArkScale<Vec<F>>>(coeffs)?;
+    let offset = decode::<ArkScale<F>>(offset)?;
+
+    let domain = radix2_coset(coeffs.0.len(), offset.0)?;
+    let result: ArkScale<Vec<F>> = domain.fft(&coeffs.0).into();
+    Ok(result.encode())
+}
+
+/// Compute the coefficients of a polynomial from its evaluations over a coset of a
+/// radix-2 domain of `F`.
+pub fn ifft<F: FftField>(evals: &[u8], offset: &[u8]) -> Result<Vec<u8>, ()> {
+    let evals = decode::<ArkScale<Vec<F>>>(evals)?;
+    let offset = decode::<ArkScale<F>>(offset)?;
+
+    let domain = radix2_coset(evals.0.len(), offset.0)?;
+    let result: ArkScale<Vec<F>> = domain.ifft(&evals.0).into();
+    Ok(result.encode())
+}
+
+fn radix2_coset<F: FftField>(size: usize, offset: F) -> Result<Radix2EvaluationDomain<F>, ()> {
+    if !size.is_power_of_two() {
+        return Err(());
+    }
+    Radix2EvaluationDomain::new_coset(size, offset).ok_or(())
+}
+
+/// Invert every nonzero element of a sequence of elements of `F`.
+pub fn batch_inverse<F: Field>(values: &[u8]) -> Result<Vec<u8>, ()> {
+    let mut values = decode::<ArkScale<Vec<F>>>(values)?;
+
+    batch_inversion(&mut values.0);
+
+    Ok(values.encode())
+}
+
+/// Hash `msg` to a short Weierstrass curve with the `expand_message_xmd` SHA-256
+/// simplified SWU random oracle suite of RFC 9380, using `dst` as domain separation tag.
+pub fn hash_to_curve_sw<Curve: WBConfig>(msg: &[u8], dst: &[u8]) -> Result<Vec<u8>, ()> {
+    let hasher = MapToCurveBasedHasher::<
+        SWProjective<Curve>,
+        DefaultFieldHasher<Sha256, 128>,
+        WBMap<Curve>,
+    >::new(dst)
+    .map_err(|_| ())?;
+
+    let result = hasher.hash(msg).map_err(|_| ())?;
+
+    let result: ArkScale<SWAffine<Curve>> = result.into();
+    Ok(result.encode())
+}
+
+/// Call `host` with `args`, computing the result with `software` instead if the
+/// host call fails, or without calling `host` at all if `software_only`.
+///
+/// The arguments are borrowed, so that only `host` copies them into the owned
+/// buffers the host functions take.
+pub fn with_fallback<A: Copy, R>(
+    software_only: bool,
+    args: A,
+    host: impl FnOnce(A) -> Result<R, ()>,
+    software: impl FnOnce(A) -> Result<R, ()>,
+) -> Result<R, ()> {
+    if software_only {
+        return software(args);
+    }
+    host(args).or_else(|_| software(args))
+}
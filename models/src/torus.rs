@@ -0,0 +1,142 @@
+//! Torus compression of target group elements.
+//!
+//! The target groups of the pairing curves lie in the cyclotomic subgroup of a
+//! quadratic extension `F[w]`, `w² = v`, whose elements `g = g0 + g1·w` have norm
+//! `g0² - v·g1² = 1`. Any such element other than `±1` is `g = (c + w) / (c - w)`
+//! for `c = (1 + g0) / g1`, so it is represented by `c ∈ F`, half the size of `g`
+//! (the T2 torus). One is represented by `c = 0`, which would otherwise stand for
+//! `-1`, never in a subgroup of odd order.
+
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ff::{Field, One, QuadExtConfig, QuadExtField, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use codec::Decode;
+use derivative::Derivative;
+
+use crate::HostCallError;
+
+const HOST_CALL: ark_scale::Usage = ark_scale::HOST_CALL;
+type ArkScale<T> = ark_scale::ArkScale<T, HOST_CALL>;
+
+/// Field whose cyclotomic subgroup elements can be torus compressed.
+pub trait TorusCompression: Field {
+    /// Field of the compressed representations.
+    type Compressed: Field;
+
+    /// Compressed representation of `self`, `None` if `self` is `-1` or does not
+    /// have norm one.
+    fn torus_compress(&self) -> Option<Self::Compressed>;
+
+    /// Element represented by `c`.
+    fn torus_decompress(c: &Self::Compressed) -> Self;
+}
+
+impl<P: QuadExtConfig> TorusCompression for QuadExtField<P> {
+    type Compressed = P::BaseField;
+
+    fn torus_compress(&self) -> Option<P::BaseField> {
+        if self.is_one() {
+            return Some(P::BaseField::zero());
+        }
+        if !self.norm().is_one() {
+            return None;
+        }
+        Some((P::BaseField::one() + self.c0) * self.c1.inverse()?)
+    }
+
+    fn torus_decompress(c: &P::BaseField) -> Self {
+        if c.is_zero() {
+            return Self::one();
+        }
+        // (c + w) / (c - w) = (c² + v + 2c·w) / (c² - v), with c² - v never zero as v
+        // is not a square.
+        let square = c.square();
+        let inverse = (square - P::NONRESIDUE)
+            .inverse()
+            .expect("the non residue is not a square");
+        Self::new((square + P::NONRESIDUE) * inverse, c.double() * inverse)
+    }
+}
+
+/// Target group element serialized with torus compression.
+///
+/// Serializing an element outside of the cyclotomic subgroup fails. Deserializing
+/// with validation checks that the element is in the target group.
+#[derive(Derivative)]
+#[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Compressed<E: Pairing>(pub PairingOutput<E>);
+
+impl<E: Pairing> From<PairingOutput<E>> for Compressed<E> {
+    fn from(value: PairingOutput<E>) -> Self {
+        Compressed(value)
+    }
+}
+
+impl<E: Pairing> Valid for Compressed<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
+}
+
+impl<E: Pairing> CanonicalSerialize for Compressed<E>
+where
+    E::TargetField: TorusCompression,
+{
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.0
+             .0
+            .torus_compress()
+            .ok_or(SerializationError::InvalidData)?
+            .serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        <E::TargetField as TorusCompression>::Compressed::zero().serialized_size(compress)
+    }
+}
+
+impl<E: Pairing> CanonicalDeserialize for Compressed<E>
+where
+    E::TargetField: TorusCompression,
+{
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c = <E::TargetField as TorusCompression>::Compressed::deserialize_with_mode(
+            reader, compress, validate,
+        )?;
+        let result = Compressed(PairingOutput(E::TargetField::torus_decompress(&c)));
+        if validate == Validate::Yes {
+            result.check()?;
+        }
+        Ok(result)
+    }
+}
+
+/// Decode a target group element returned by a host call, accepting both the torus
+/// compressed encoding and the full one of hosts predating it.
+pub fn decode_host_output<E: Pairing>(encoded: &[u8]) -> Result<PairingOutput<E>, HostCallError>
+where
+    E::TargetField: TorusCompression,
+{
+    let compressed_size =
+        <E::TargetField as TorusCompression>::Compressed::zero().serialized_size(Compress::No);
+    if encoded.len() == compressed_size {
+        <ArkScale<Compressed<E>> as Decode>::decode(&mut &encoded[..])
+            .map(|result| result.0 .0)
+            .map_err(|_| HostCallError::DecodeFailed)
+    } else {
+        <ArkScale<PairingOutput<E>> as Decode>::decode(&mut &encoded[..])
+            .map(|result| result.0)
+            .map_err(|_| HostCallError::DecodeFailed)
+    }
+}
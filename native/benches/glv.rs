@@ -0,0 +1,75 @@
+//! Software scalar multiplications with the GLV method, as computed by `Fallback`
+//! when the host functions are unavailable, against the double and add of upstream
+//! arkworks.
+//!
+//! Run with `cargo bench -p sp-ark-native --bench glv`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_std::{test_rng, UniformRand};
+
+const ITERATIONS: usize = 200;
+
+/// Average time of `mul` over `inputs`.
+fn time<I, R>(inputs: &[I], mul: impl Fn(&I) -> R) -> Duration {
+    let start = Instant::now();
+    for input in inputs {
+        black_box(mul(black_box(input)));
+    }
+    start.elapsed() / inputs.len() as u32
+}
+
+/// Time the upstream multiplication of the affine points and the GLV one of the
+/// same points on the host-shaped curve.
+fn compare<A: AffineRepr, G: CurveGroup<ScalarField = A::ScalarField>>(
+    name: &str,
+    point: impl Fn(&A) -> G,
+    glv_mul: impl Fn(&G, &A::ScalarField) -> G,
+) {
+    let mut rng = test_rng();
+    let inputs = (0..ITERATIONS)
+        .map(|_| {
+            let p = A::Group::rand(&mut rng).into_affine();
+            (p, point(&p), A::ScalarField::rand(&mut rng))
+        })
+        .collect::<Vec<_>>();
+
+    let upstream = time(&inputs, |(p, _, s)| *p * s);
+    let glv = time(&inputs, |(_, q, s)| glv_mul(q, s));
+    println!(
+        "{name:<32} upstream {upstream:>10.2?}  glv {glv:>10.2?}  speedup {:.2}x",
+        upstream.as_secs_f64() / glv.as_secs_f64()
+    );
+}
+
+fn main() {
+    use sp_ark_bls12_381::{g1, G1Affine};
+    use sp_ark_ed_on_bls12_381_bandersnatch::{BandersnatchConfig, EdwardsAffine, SWAffine};
+
+    type Bls12_381 = sp_ark_native::bls12_381::Host;
+    type Bandersnatch = sp_ark_native::ed_on_bls12_381_bandersnatch::Host;
+
+    compare(
+        "bls12_381 G1",
+        |p: &ark_bls12_381::G1Affine| G1Affine::<Bls12_381>::new_unchecked(p.x, p.y).into_group(),
+        g1::Config::<Bls12_381>::glv_mul,
+    );
+    compare(
+        "ed_on_bls12_381_bandersnatch SW",
+        |p: &ark_ed_on_bls12_381_bandersnatch::SWAffine| {
+            SWAffine::<Bandersnatch>::new_unchecked(p.x, p.y).into_group()
+        },
+        BandersnatchConfig::<Bandersnatch>::glv_mul_sw,
+    );
+    compare(
+        "ed_on_bls12_381_bandersnatch TE",
+        |p: &ark_ed_on_bls12_381_bandersnatch::EdwardsAffine| {
+            EdwardsAffine::<Bandersnatch>::new_unchecked(p.x, p.y).into_group()
+        },
+        BandersnatchConfig::<Bandersnatch>::glv_mul_te,
+    );
+}
@@ -0,0 +1,163 @@
+use ark_bls12_377::{g1, g2, Bls12_377, Fr};
+use ark_std::vec::Vec;
+
+use sp_ark_models::software;
+
+/// Native implementation of [`sp_ark_bls12_377::HostFunctions`].
+pub struct Host;
+
+impl sp_ark_bls12_377::HostFunctions for Host {
+    fn bls12_377_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        multi_miller_loop(a, b)
+    }
+    fn bls12_377_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        final_exponentiation(f12)
+    }
+    fn bls12_377_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        pairing_check(a, b)
+    }
+    fn bls12_377_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_g1(bases, scalars)
+    }
+    fn bls12_377_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_g2(bases, scalars)
+    }
+    fn bls12_377_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_projective_g1(base, scalar)
+    }
+    fn bls12_377_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_projective_g2(base, scalar)
+    }
+    fn bls12_377_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_gt(base, scalar)
+    }
+    fn bls12_377_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_gt(bases, scalars)
+    }
+    fn bls12_377_fft(coeffs: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        fft(coeffs, offset)
+    }
+    fn bls12_377_ifft(evals: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        ifft(evals, offset)
+    }
+    fn bls12_377_batch_inverse(values: Vec<u8>) -> Result<Vec<u8>, ()> {
+        batch_inverse(values)
+    }
+    fn bls12_377_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_small_g1(bases, scalars)
+    }
+    fn bls12_377_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_small_g2(bases, scalars)
+    }
+    fn bls12_377_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        batch(calls)
+    }
+    fn bls12_377_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        normalize_g1(points)
+    }
+    fn bls12_377_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        normalize_g2(points)
+    }
+    fn bls12_377_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        deserialize_g1(compressed)
+    }
+    fn bls12_377_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        deserialize_g2(compressed)
+    }
+}
+
+/// Compute a multi Miller loop on BLS12-377.
+pub fn multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::multi_miller_loop::<Bls12_377>(&a, &b)
+}
+
+/// Compute a final exponentiation on BLS12-377.
+pub fn final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::final_exponentiation::<Bls12_377>(&f12)
+}
+
+/// Check whether the product of the pairings on BLS12-377 is one.
+pub fn pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+    software::pairing_check::<Bls12_377>(&a, &b)
+}
+
+/// Compute a multi scalar multiplication on G1 for BLS12-377.
+pub fn msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_sw::<g1::Config>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication on G2 for BLS12-377.
+pub fn msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_sw::<g2::Config>(&bases, &scalars)
+}
+
+/// Compute a projective scalar multiplication on G1 for BLS12-377.
+pub fn mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_sw::<g1::Config>(&base, &scalar)
+}
+
+/// Compute a projective scalar multiplication on G2 for BLS12-377.
+pub fn mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_sw::<g2::Config>(&base, &scalar)
+}
+
+/// Compute a scalar multiplication in the target group of BLS12-377.
+pub fn mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_gt::<Bls12_377>(&base, &scalar)
+}
+
+/// Compute a multi scalar multiplication in the target group of BLS12-377.
+pub fn msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_gt::<Bls12_377>(&bases, &scalars)
+}
+
+/// Compute an FFT over a coset of a radix-2 domain of the scalar field of BLS12-377.
+pub fn fft(coeffs: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::fft::<Fr>(&coeffs, &offset)
+}
+
+/// Compute an inverse FFT over a coset of a radix-2 domain of the scalar field of
+/// BLS12-377.
+pub fn ifft(evals: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::ifft::<Fr>(&evals, &offset)
+}
+
+/// Invert every nonzero element of a sequence of scalar field elements of BLS12-377.
+pub fn batch_inverse(values: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::batch_inverse::<Fr>(&values)
+}
+
+/// Compute a multi scalar multiplication by small scalars on G1 for BLS12-377.
+pub fn msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_sw::<g1::Config>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on G2 for BLS12-377.
+pub fn msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_sw::<g2::Config>(&bases, &scalars)
+}
+
+/// Compute a batch of independent host calls on BLS12-377, one after the other.
+pub fn batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+    sp_ark_models::batch::execute(&calls, sp_ark_bls12_377::batch::dispatch::<Host>)
+}
+
+/// Normalise projective points of G1 to affine for BLS12-377.
+pub fn normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::normalize_sw::<g1::Config>(&points)
+}
+
+/// Normalise projective points of G2 to affine for BLS12-377.
+pub fn normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::normalize_sw::<g2::Config>(&points)
+}
+
+/// Deserialize and check a vector of compressed points of G1 for BLS12-377.
+pub fn deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::deserialize_batch::<ark_bls12_377::G1Affine>(&compressed)
+}
+
+/// Deserialize and check a vector of compressed points of G2 for BLS12-377.
+pub fn deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::deserialize_batch::<ark_bls12_377::G2Affine>(&compressed)
+}
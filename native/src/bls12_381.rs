@@ -0,0 +1,206 @@
+use ark_bls12_381::{g1, g2, Bls12_381, Fr};
+use ark_std::vec::Vec;
+
+use sp_ark_models::software;
+
+#[cfg(feature = "std")]
+use crate::registry::Registry;
+
+/// Native implementation of [`sp_ark_bls12_381::HostFunctions`].
+pub struct Host;
+
+impl sp_ark_bls12_381::HostFunctions for Host {
+    fn bls12_381_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        multi_miller_loop(a, b)
+    }
+    fn bls12_381_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        final_exponentiation(f12)
+    }
+    fn bls12_381_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        pairing_check(a, b)
+    }
+    fn bls12_381_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_g1(bases, scalars)
+    }
+    fn bls12_381_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_g2(bases, scalars)
+    }
+    fn bls12_381_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_projective_g1(base, scalar)
+    }
+    fn bls12_381_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_projective_g2(base, scalar)
+    }
+    fn bls12_381_hash_to_g1(msg: Vec<u8>, dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+        hash_to_g1(msg, dst)
+    }
+    fn bls12_381_hash_to_g2(msg: Vec<u8>, dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+        hash_to_g2(msg, dst)
+    }
+    fn bls12_381_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_gt(base, scalar)
+    }
+    fn bls12_381_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_gt(bases, scalars)
+    }
+    fn bls12_381_fft(coeffs: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        fft(coeffs, offset)
+    }
+    fn bls12_381_ifft(evals: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+        ifft(evals, offset)
+    }
+    fn bls12_381_batch_inverse(values: Vec<u8>) -> Result<Vec<u8>, ()> {
+        batch_inverse(values)
+    }
+    #[cfg(feature = "std")]
+    fn bls12_381_register_bases_g1(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        register_bases_g1(bases)
+    }
+    #[cfg(feature = "std")]
+    fn bls12_381_msm_with_handle_g1(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_with_handle_g1(handle, scalars)
+    }
+    fn bls12_381_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_small_g1(bases, scalars)
+    }
+    fn bls12_381_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_small_g2(bases, scalars)
+    }
+    fn bls12_381_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        batch(calls)
+    }
+    fn bls12_381_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        normalize_g1(points)
+    }
+    fn bls12_381_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        normalize_g2(points)
+    }
+    fn bls12_381_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        deserialize_g1(compressed)
+    }
+    fn bls12_381_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        deserialize_g2(compressed)
+    }
+}
+
+#[cfg(feature = "std")]
+static G1_BASES: Registry<ark_bls12_381::G1Affine> = Registry::new();
+
+/// Compute a multi Miller loop on BLS12-381.
+pub fn multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::multi_miller_loop::<Bls12_381>(&a, &b)
+}
+
+/// Compute a final exponentiation on BLS12-381.
+pub fn final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::final_exponentiation::<Bls12_381>(&f12)
+}
+
+/// Check whether the product of the pairings on BLS12-381 is one.
+pub fn pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+    software::pairing_check::<Bls12_381>(&a, &b)
+}
+
+/// Compute a multi scalar multiplication on G1 for BLS12-381.
+pub fn msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_sw::<g1::Config>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication on G2 for BLS12-381.
+pub fn msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_sw::<g2::Config>(&bases, &scalars)
+}
+
+/// Compute a projective scalar multiplication on G1 for BLS12-381.
+pub fn mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_sw::<g1::Config>(&base, &scalar)
+}
+
+/// Compute a projective scalar multiplication on G2 for BLS12-381.
+pub fn mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_sw::<g2::Config>(&base, &scalar)
+}
+
+/// Hash a message to G1 for BLS12-381.
+pub fn hash_to_g1(msg: Vec<u8>, dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::hash_to_curve_sw::<g1::Config>(&msg, &dst)
+}
+
+/// Hash a message to G2 for BLS12-381.
+pub fn hash_to_g2(msg: Vec<u8>, dst: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::hash_to_curve_sw::<g2::Config>(&msg, &dst)
+}
+
+/// Compute a scalar multiplication in the target group of BLS12-381.
+pub fn mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_gt::<Bls12_381>(&base, &scalar)
+}
+
+/// Compute a multi scalar multiplication in the target group of BLS12-381.
+pub fn msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_gt::<Bls12_381>(&bases, &scalars)
+}
+
+/// Compute an FFT over a coset of a radix-2 domain of the scalar field of BLS12-381.
+pub fn fft(coeffs: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::fft::<Fr>(&coeffs, &offset)
+}
+
+/// Compute an inverse FFT over a coset of a radix-2 domain of the scalar field of
+/// BLS12-381.
+pub fn ifft(evals: Vec<u8>, offset: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::ifft::<Fr>(&evals, &offset)
+}
+
+/// Invert every nonzero element of a sequence of scalar field elements of BLS12-381.
+pub fn batch_inverse(values: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::batch_inverse::<Fr>(&values)
+}
+
+/// Register a sequence of bases of G1 for BLS12-381, returning their handle.
+#[cfg(feature = "std")]
+pub fn register_bases_g1(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+    G1_BASES.insert(software::register_bases(&bases)?)
+}
+
+/// Compute a multi scalar multiplication on G1 for BLS12-381 of the first bases
+/// registered under a handle.
+#[cfg(feature = "std")]
+pub fn msm_with_handle_g1(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_with_bases_sw::<g1::Config>(&G1_BASES.get(&handle)?, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on G1 for BLS12-381.
+pub fn msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_sw::<g1::Config>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on G2 for BLS12-381.
+pub fn msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_sw::<g2::Config>(&bases, &scalars)
+}
+
+/// Compute a batch of independent host calls on BLS12-381, one after the other.
+pub fn batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+    sp_ark_models::batch::execute(&calls, sp_ark_bls12_381::batch::dispatch::<Host>)
+}
+
+/// Normalise projective points of G1 to affine for BLS12-381.
+pub fn normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::normalize_sw::<g1::Config>(&points)
+}
+
+/// Normalise projective points of G2 to affine for BLS12-381.
+pub fn normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::normalize_sw::<g2::Config>(&points)
+}
+
+/// Deserialize and check a vector of compressed points of G1 for BLS12-381.
+pub fn deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::deserialize_batch::<ark_bls12_381::G1Affine>(&compressed)
+}
+
+/// Deserialize and check a vector of compressed points of G2 for BLS12-381.
+pub fn deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::deserialize_batch::<ark_bls12_381::G2Affine>(&compressed)
+}
@@ -0,0 +1,138 @@
+use ark_bw6_761::{g1, g2, BW6_761};
+use ark_std::vec::Vec;
+
+use sp_ark_models::software;
+
+/// Native implementation of [`sp_ark_bw6_761::HostFunctions`].
+pub struct Host;
+
+impl sp_ark_bw6_761::HostFunctions for Host {
+    fn bw6_761_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        multi_miller_loop(a, b)
+    }
+    fn bw6_761_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        final_exponentiation(f12)
+    }
+    fn bw6_761_pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+        pairing_check(a, b)
+    }
+    fn bw6_761_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_g1(bases, scalars)
+    }
+    fn bw6_761_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_g2(bases, scalars)
+    }
+    fn bw6_761_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_projective_g1(base, scalar)
+    }
+    fn bw6_761_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_projective_g2(base, scalar)
+    }
+    fn bw6_761_mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_gt(base, scalar)
+    }
+    fn bw6_761_msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_gt(bases, scalars)
+    }
+    fn bw6_761_msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_small_g1(bases, scalars)
+    }
+    fn bw6_761_msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_small_g2(bases, scalars)
+    }
+    fn bw6_761_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        batch(calls)
+    }
+    fn bw6_761_normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        normalize_g1(points)
+    }
+    fn bw6_761_normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+        normalize_g2(points)
+    }
+    fn bw6_761_deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        deserialize_g1(compressed)
+    }
+    fn bw6_761_deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+        deserialize_g2(compressed)
+    }
+}
+
+/// Compute a multi Miller loop on BW6-761.
+pub fn multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::multi_miller_loop::<BW6_761>(&a, &b)
+}
+
+/// Compute a final exponentiation on BW6-761.
+pub fn final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::final_exponentiation::<BW6_761>(&f12)
+}
+
+/// Check whether the product of the pairings on BW6-761 is one.
+pub fn pairing_check(a: Vec<u8>, b: Vec<u8>) -> Result<bool, ()> {
+    software::pairing_check::<BW6_761>(&a, &b)
+}
+
+/// Compute a multi scalar multiplication on G1 for BW6-761.
+pub fn msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_sw::<g1::Config>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication on G2 for BW6-761.
+pub fn msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_sw::<g2::Config>(&bases, &scalars)
+}
+
+/// Compute a projective scalar multiplication on G1 for BW6-761.
+pub fn mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_sw::<g1::Config>(&base, &scalar)
+}
+
+/// Compute a projective scalar multiplication on G2 for BW6-761.
+pub fn mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_sw::<g2::Config>(&base, &scalar)
+}
+
+/// Compute a scalar multiplication in the target group of BW6-761.
+pub fn mul_gt(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_gt::<BW6_761>(&base, &scalar)
+}
+
+/// Compute a multi scalar multiplication in the target group of BW6-761.
+pub fn msm_gt(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_gt::<BW6_761>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on G1 for BW6-761.
+pub fn msm_small_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_sw::<g1::Config>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on G2 for BW6-761.
+pub fn msm_small_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_sw::<g2::Config>(&bases, &scalars)
+}
+
+/// Compute a batch of independent host calls on BW6-761, one after the other.
+pub fn batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+    sp_ark_models::batch::execute(&calls, sp_ark_bw6_761::batch::dispatch::<Host>)
+}
+
+/// Normalise projective points of G1 to affine for BW6-761.
+pub fn normalize_g1(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::normalize_sw::<g1::Config>(&points)
+}
+
+/// Normalise projective points of G2 to affine for BW6-761.
+pub fn normalize_g2(points: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::normalize_sw::<g2::Config>(&points)
+}
+
+/// Deserialize and check a vector of compressed points of G1 for BW6-761.
+pub fn deserialize_g1(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::deserialize_batch::<ark_bw6_761::G1Affine>(&compressed)
+}
+
+/// Deserialize and check a vector of compressed points of G2 for BW6-761.
+pub fn deserialize_g2(compressed: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::deserialize_batch::<ark_bw6_761::G2Affine>(&compressed)
+}
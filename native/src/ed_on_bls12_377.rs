@@ -0,0 +1,69 @@
+use ark_ed_on_bls12_377::EdwardsConfig;
+use ark_std::vec::Vec;
+
+use sp_ark_models::software;
+
+#[cfg(feature = "std")]
+use crate::registry::Registry;
+
+/// Native implementation of [`sp_ark_ed_on_bls12_377::HostFunctions`].
+pub struct Host;
+
+impl sp_ark_ed_on_bls12_377::HostFunctions for Host {
+    fn ed_on_bls12_377_msm(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm(bases, scalars)
+    }
+    fn ed_on_bls12_377_mul_projective(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        mul_projective(base, scalar)
+    }
+    #[cfg(feature = "std")]
+    fn ed_on_bls12_377_register_bases(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        register_bases(bases)
+    }
+    #[cfg(feature = "std")]
+    fn ed_on_bls12_377_msm_with_handle(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_with_handle(handle, scalars)
+    }
+    fn ed_on_bls12_377_msm_small(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        msm_small(bases, scalars)
+    }
+    fn ed_on_bls12_377_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        batch(calls)
+    }
+}
+
+#[cfg(feature = "std")]
+static BASES: Registry<ark_ed_on_bls12_377::EdwardsAffine> = Registry::new();
+
+/// Compute a multi scalar multiplication on Ed-on-BLS12-377.
+pub fn msm(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_te::<EdwardsConfig>(&bases, &scalars)
+}
+
+/// Compute a projective scalar multiplication on Ed-on-BLS12-377.
+pub fn mul_projective(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_te::<EdwardsConfig>(&base, &scalar)
+}
+
+/// Register a sequence of bases of Ed-on-BLS12-377, returning their handle.
+#[cfg(feature = "std")]
+pub fn register_bases(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+    BASES.insert(software::register_bases(&bases)?)
+}
+
+/// Compute a multi scalar multiplication on Ed-on-BLS12-377 of the first bases
+/// registered under a handle.
+#[cfg(feature = "std")]
+pub fn msm_with_handle(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_with_bases_te::<EdwardsConfig>(&BASES.get(&handle)?, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on Ed-on-BLS12-377.
+pub fn msm_small(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_te::<EdwardsConfig>(&bases, &scalars)
+}
+
+/// Compute a batch of independent host calls on Ed-on-BLS12-377, one after the other.
+pub fn batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+    sp_ark_models::batch::execute(&calls, sp_ark_ed_on_bls12_377::batch::dispatch::<Host>)
+}
@@ -0,0 +1,118 @@
+use ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig;
+use ark_std::vec::Vec;
+
+use sp_ark_models::software;
+
+#[cfg(feature = "std")]
+use crate::registry::Registry;
+
+/// Native implementation of [`sp_ark_ed_on_bls12_381_bandersnatch::HostFunctions`].
+pub struct Host;
+
+impl sp_ark_ed_on_bls12_381_bandersnatch::HostFunctions for Host {
+    fn ed_on_bls12_381_bandersnatch_te_msm(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        te_msm(bases, scalars)
+    }
+    fn ed_on_bls12_381_bandersnatch_sw_msm(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        sw_msm(bases, scalars)
+    }
+    fn ed_on_bls12_381_bandersnatch_sw_mul_projective(
+        base: Vec<u8>,
+        scalar: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        sw_mul_projective(base, scalar)
+    }
+    fn ed_on_bls12_381_bandersnatch_te_mul_projective(
+        base: Vec<u8>,
+        scalar: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        te_mul_projective(base, scalar)
+    }
+    #[cfg(feature = "std")]
+    fn ed_on_bls12_381_bandersnatch_te_register_bases(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+        te_register_bases(bases)
+    }
+    #[cfg(feature = "std")]
+    fn ed_on_bls12_381_bandersnatch_te_msm_with_handle(
+        handle: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        te_msm_with_handle(handle, scalars)
+    }
+    fn ed_on_bls12_381_bandersnatch_te_msm_small(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        te_msm_small(bases, scalars)
+    }
+    fn ed_on_bls12_381_bandersnatch_sw_msm_small(
+        bases: Vec<u8>,
+        scalars: Vec<u8>,
+    ) -> Result<Vec<u8>, ()> {
+        sw_msm_small(bases, scalars)
+    }
+    fn ed_on_bls12_381_bandersnatch_batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+        batch(calls)
+    }
+}
+
+#[cfg(feature = "std")]
+static TE_BASES: Registry<ark_ed_on_bls12_381_bandersnatch::EdwardsAffine> = Registry::new();
+
+/// Compute a multi scalar multiplication on Bandersnatch, twisted Edwards form.
+pub fn te_msm(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_te::<BandersnatchConfig>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication on Bandersnatch, short Weierstrass form.
+pub fn sw_msm(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_sw::<BandersnatchConfig>(&bases, &scalars)
+}
+
+/// Compute a projective scalar multiplication on Bandersnatch, twisted Edwards form.
+pub fn te_mul_projective(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_te::<BandersnatchConfig>(&base, &scalar)
+}
+
+/// Compute a projective scalar multiplication on Bandersnatch, short Weierstrass form.
+pub fn sw_mul_projective(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::mul_projective_sw::<BandersnatchConfig>(&base, &scalar)
+}
+
+/// Register a sequence of bases of Bandersnatch, twisted Edwards form, returning
+/// their handle.
+#[cfg(feature = "std")]
+pub fn te_register_bases(bases: Vec<u8>) -> Result<Vec<u8>, ()> {
+    TE_BASES.insert(software::register_bases(&bases)?)
+}
+
+/// Compute a multi scalar multiplication on Bandersnatch, twisted Edwards form, of
+/// the first bases registered under a handle.
+#[cfg(feature = "std")]
+pub fn te_msm_with_handle(handle: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_with_bases_te::<BandersnatchConfig>(&TE_BASES.get(&handle)?, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on Bandersnatch, twisted Edwards form.
+pub fn te_msm_small(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_te::<BandersnatchConfig>(&bases, &scalars)
+}
+
+/// Compute a multi scalar multiplication by small scalars on Bandersnatch, short Weierstrass form.
+pub fn sw_msm_small(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+    software::msm_small_sw::<BandersnatchConfig>(&bases, &scalars)
+}
+
+/// Compute a batch of independent host calls on Bandersnatch, one after the other.
+pub fn batch(calls: Vec<u8>) -> Result<Vec<u8>, ()> {
+    sp_ark_models::batch::execute(
+        &calls,
+        sp_ark_ed_on_bls12_381_bandersnatch::batch::dispatch::<Host>,
+    )
+}
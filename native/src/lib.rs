@@ -0,0 +1,31 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(
+    warnings,
+    unused,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_unit_err)]
+#![forbid(unsafe_code)]
+
+//! Native implementation of the elliptic curve host functions required by the
+//! `sp-ark-*` curve crates.
+//!
+//! Every function decodes its `ArkScale` host call arguments, computes the
+//! result with the upstream arkworks curve implementation and re-encodes it, so
+//! it can be used wherever the runtime host functions are not available: in
+//! off-chain services, tools and tests. Each curve module exposes a `Host` type
+//! implementing the curve crate's `HostFunctions` trait, e.g.
+//! `sp_ark_bls12_381::Bls12_381<sp_ark_native::bls12_381::Host>`.
+
+pub mod bls12_377;
+pub mod bls12_381;
+pub mod bw6_761;
+pub mod ed_on_bls12_377;
+pub mod ed_on_bls12_381_bandersnatch;
+#[cfg(feature = "std")]
+mod registry;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,95 @@
+//! Bases registered with the native hosts.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    vec::Vec,
+};
+
+use sp_ark_models::registry::Handle;
+
+/// Number of bases a registry keeps in total, about 100 MiB of BLS12-381 G1 points.
+const MAX_BASES: usize = 1 << 20;
+
+/// Bases registered under their handle, for the lifetime of the process.
+///
+/// Once more than its capacity of bases are registered, the sets registered first
+/// are dropped, after which MSMs with their handle fail and callers fall back to
+/// plain MSMs.
+pub(crate) struct Registry<A> {
+    sets: Mutex<VecDeque<(Handle, Arc<Vec<A>>)>>,
+    max_bases: usize,
+}
+
+impl<A> Registry<A> {
+    pub(crate) const fn new() -> Self {
+        Self::with_capacity(MAX_BASES)
+    }
+
+    /// Registry keeping at most `max_bases` bases in total.
+    pub(crate) const fn with_capacity(max_bases: usize) -> Self {
+        Registry {
+            sets: Mutex::new(VecDeque::new()),
+            max_bases,
+        }
+    }
+
+    /// Register the decoded `bases` under `handle`, returning the encoded handle.
+    pub(crate) fn insert(&self, (handle, bases): (Handle, Vec<A>)) -> Result<Vec<u8>, ()> {
+        if bases.len() > self.max_bases {
+            return Err(());
+        }
+        let mut sets = self.sets.lock().map_err(|_| ())?;
+        sets.retain(|(registered, _)| *registered != handle);
+        let mut len = sets.iter().map(|(_, bases)| bases.len()).sum::<usize>() + bases.len();
+        while len > self.max_bases {
+            let (_, dropped) = sets.pop_front().expect("the new bases fit on their own");
+            len -= dropped.len();
+        }
+        sets.push_back((handle, Arc::new(bases)));
+        Ok(handle.to_vec())
+    }
+
+    /// Bases registered under the encoded `handle`.
+    pub(crate) fn get(&self, handle: &[u8]) -> Result<Arc<Vec<A>>, ()> {
+        let handle = Handle::try_from(handle).map_err(|_| ())?;
+        self.sets
+            .lock()
+            .map_err(|_| ())?
+            .iter()
+            .find(|(registered, _)| *registered == handle)
+            .map(|(_, bases)| bases.clone())
+            .ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+
+    #[test]
+    fn drops_oldest_bases() {
+        let registry = Registry::<u8>::with_capacity(4);
+        let handle = |i: u8| [i; 32];
+
+        assert_eq!(
+            registry.insert((handle(0), vec![0; 2])),
+            Ok(handle(0).to_vec())
+        );
+        assert!(registry.insert((handle(1), vec![1; 2])).is_ok());
+        assert_eq!(*registry.get(&handle(0)).unwrap(), [0; 2]);
+
+        // Registering again moves the bases to the back of the queue.
+        assert!(registry.insert((handle(0), vec![0; 2])).is_ok());
+        assert!(registry.insert((handle(2), vec![2; 1])).is_ok());
+        assert_eq!(registry.get(&handle(1)), Err(()));
+        assert_eq!(*registry.get(&handle(0)).unwrap(), [0; 2]);
+        assert_eq!(*registry.get(&handle(2)).unwrap(), [2; 1]);
+
+        assert_eq!(registry.insert((handle(3), vec![3; 5])), Err(()));
+        assert!(registry.insert((handle(3), vec![3; 4])).is_ok());
+        assert_eq!(registry.get(&handle(0)), Err(()));
+        assert_eq!(registry.get(&handle(2)), Err(()));
+        assert_eq!(registry.get(&handle(3)[..31]), Err(()));
+    }
+}
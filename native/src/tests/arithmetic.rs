@@ -0,0 +1,101 @@
+//! Scalar multiplications and MSMs, compared with the upstream arkworks curves.
+//!
+//! The host-shaped curves and the upstream arkworks curves share their field types,
+//! so points are compared coordinate-wise.
+
+use ark_ec::{
+    short_weierstrass::{self as sw, SWCurveConfig},
+    twisted_edwards::{self as te, TECurveConfig},
+    AffineRepr, CurveGroup, Group, VariableBaseMSM,
+};
+use ark_std::{test_rng, vec::Vec, UniformRand};
+
+use super::{points, random, MSM_LEN};
+
+fn check_sw<P, N>()
+where
+    P: SWCurveConfig,
+    N: SWCurveConfig<BaseField = P::BaseField, ScalarField = P::ScalarField>,
+{
+    let mut rng = test_rng();
+    let s = P::ScalarField::rand(&mut rng);
+    let p = (sw::Projective::<P>::generator() * s).into_affine();
+    let expected = (sw::Projective::<N>::generator() * s).into_affine();
+    assert_eq!(p.xy(), expected.xy());
+
+    let scalars = random(&mut rng, MSM_LEN);
+    let bases = points::<sw::Projective<P>>(&mut rng, MSM_LEN);
+    let native_bases = bases
+        .iter()
+        .map(|p| sw::Affine::<N>::new_unchecked(p.x, p.y))
+        .collect::<Vec<_>>();
+    let result = sw::Projective::msm(&bases, &scalars).unwrap().into_affine();
+    let expected = sw::Projective::msm(&native_bases, &scalars)
+        .unwrap()
+        .into_affine();
+    assert_eq!(result.xy(), expected.xy());
+}
+
+fn check_te<P, N>()
+where
+    P: TECurveConfig,
+    N: TECurveConfig<BaseField = P::BaseField, ScalarField = P::ScalarField>,
+{
+    let mut rng = test_rng();
+    let s = P::ScalarField::rand(&mut rng);
+    let p = (te::Projective::<P>::generator() * s).into_affine();
+    let expected = (te::Projective::<N>::generator() * s).into_affine();
+    assert_eq!(p.xy(), expected.xy());
+
+    let scalars = random(&mut rng, MSM_LEN);
+    let bases = points::<te::Projective<P>>(&mut rng, MSM_LEN);
+    let native_bases = bases
+        .iter()
+        .map(|p| te::Affine::<N>::new_unchecked(p.x, p.y))
+        .collect::<Vec<_>>();
+    let result = te::Projective::msm(&bases, &scalars).unwrap().into_affine();
+    let expected = te::Projective::msm(&native_bases, &scalars)
+        .unwrap()
+        .into_affine();
+    assert_eq!(result.xy(), expected.xy());
+}
+
+#[test]
+fn bls12_381() {
+    type Host = crate::bls12_381::Host;
+    check_sw::<sp_ark_bls12_381::g1::Config<Host>, ark_bls12_381::g1::Config>();
+    check_sw::<sp_ark_bls12_381::g2::Config<Host>, ark_bls12_381::g2::Config>();
+}
+
+#[test]
+fn bls12_377() {
+    type Host = crate::bls12_377::Host;
+    check_sw::<sp_ark_bls12_377::g1::Config<Host>, ark_bls12_377::g1::Config>();
+    check_sw::<sp_ark_bls12_377::g2::Config<Host>, ark_bls12_377::g2::Config>();
+}
+
+#[test]
+fn bw6_761() {
+    type Host = crate::bw6_761::Host;
+    check_sw::<sp_ark_bw6_761::g1::Config<Host>, ark_bw6_761::g1::Config>();
+    check_sw::<sp_ark_bw6_761::g2::Config<Host>, ark_bw6_761::g2::Config>();
+}
+
+#[test]
+fn ed_on_bls12_377() {
+    type Host = crate::ed_on_bls12_377::Host;
+    check_te::<sp_ark_ed_on_bls12_377::EdwardsConfig<Host>, ark_ed_on_bls12_377::EdwardsConfig>();
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    type Host = crate::ed_on_bls12_381_bandersnatch::Host;
+    check_te::<
+        sp_ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig<Host>,
+        ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig,
+    >();
+    check_sw::<
+        sp_ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig<Host>,
+        ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig,
+    >();
+}
@@ -0,0 +1,126 @@
+//! Batches of independent host calls.
+
+use ark_ec::{pairing::Pairing, AffineRepr, VariableBaseMSM};
+use ark_std::{test_rng, UniformRand};
+use sp_ark_models::HostCallError;
+
+use super::{points, random, MSM_LEN};
+
+macro_rules! test_pairing_host_batch {
+    ($name:ident, $sp:ident, $curve:ident) => {
+        #[test]
+        fn $name() {
+            use $sp::batch::{HostBatch, Output};
+
+            type Host = crate::$name::Host;
+            type Curve = $sp::$curve<Host>;
+            type G1 = <Curve as Pairing>::G1;
+            type G2 = <Curve as Pairing>::G2;
+
+            let mut rng = test_rng();
+            let p = G1::rand(&mut rng);
+            let q = G2::rand(&mut rng);
+            let s = $sp::Fr::rand(&mut rng);
+            let g1 = points::<G1>(&mut rng, MSM_LEN);
+            let g2 = points::<G2>(&mut rng, MSM_LEN);
+            let scalars = random(&mut rng, MSM_LEN);
+
+            let mut batch = HostBatch::<Host>::new();
+            assert!(batch.is_empty() && batch.execute().unwrap().is_empty());
+            batch
+                .mul_g1(&p, &s)
+                .msm_g2(&g2, &scalars)
+                .unwrap()
+                .multi_pairing(&g1[..2], &g2[..2])
+                .unwrap()
+                .mul_g2(&q, &s)
+                .msm_g1(&g1, &scalars)
+                .unwrap();
+            assert_eq!(batch.len(), 5);
+            assert_eq!(
+                batch.execute().unwrap(),
+                [
+                    Output::G1(p * s),
+                    Output::G2(G2::msm(&g2, &scalars).unwrap()),
+                    Output::Gt(Curve::multi_pairing(&g1[..2], &g2[..2])),
+                    Output::G2(q * s),
+                    Output::G1(G1::msm(&g1, &scalars).unwrap()),
+                ]
+            );
+
+            assert_eq!(
+                batch.msm_g1(&g1, &scalars[1..]).unwrap_err(),
+                HostCallError::LengthMismatch
+            );
+            assert_eq!(
+                batch.multi_pairing(&g1, &g2[1..]).unwrap_err(),
+                HostCallError::LengthMismatch
+            );
+            assert_eq!(batch.len(), 5);
+        }
+    };
+}
+
+test_pairing_host_batch!(bls12_381, sp_ark_bls12_381, Bls12_381);
+test_pairing_host_batch!(bls12_377, sp_ark_bls12_377, Bls12_377);
+test_pairing_host_batch!(bw6_761, sp_ark_bw6_761, BW6_761);
+
+#[test]
+fn ed_on_bls12_377() {
+    use sp_ark_ed_on_bls12_377::{batch::HostBatch, EdwardsProjective};
+
+    type Host = crate::ed_on_bls12_377::Host;
+    let mut rng = test_rng();
+    let bases = points::<EdwardsProjective<Host>>(&mut rng, MSM_LEN);
+    let scalars = random(&mut rng, MSM_LEN);
+
+    let mut batch = HostBatch::<Host>::new();
+    batch
+        .mul(&bases[0].into_group(), &scalars[0])
+        .msm(&bases, &scalars)
+        .unwrap();
+    assert_eq!(batch.len(), 2);
+    assert_eq!(
+        batch.execute().unwrap(),
+        [
+            bases[0] * scalars[0],
+            EdwardsProjective::<Host>::msm(&bases, &scalars).unwrap(),
+        ]
+    );
+    assert_eq!(
+        batch.msm(&bases, &scalars[1..]).unwrap_err(),
+        HostCallError::LengthMismatch
+    );
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    use sp_ark_ed_on_bls12_381_bandersnatch::{
+        batch::{HostBatch, Output},
+        EdwardsProjective, SWProjective,
+    };
+
+    type Host = crate::ed_on_bls12_381_bandersnatch::Host;
+    let mut rng = test_rng();
+    let te = points::<EdwardsProjective<Host>>(&mut rng, MSM_LEN);
+    let sw = points::<SWProjective<Host>>(&mut rng, MSM_LEN);
+    let scalars = random(&mut rng, MSM_LEN);
+
+    let mut batch = HostBatch::<Host>::new();
+    batch
+        .te_mul(&te[0].into_group(), &scalars[0])
+        .sw_mul(&sw[0].into_group(), &scalars[0])
+        .te_msm(&te, &scalars)
+        .unwrap()
+        .sw_msm(&sw, &scalars)
+        .unwrap();
+    assert_eq!(
+        batch.execute().unwrap(),
+        [
+            Output::Te(te[0] * scalars[0]),
+            Output::Sw(sw[0] * scalars[0]),
+            Output::Te(EdwardsProjective::<Host>::msm(&te, &scalars).unwrap()),
+            Output::Sw(SWProjective::<Host>::msm(&sw, &scalars).unwrap()),
+        ]
+    );
+}
@@ -0,0 +1,48 @@
+//! Batched deserialization of compressed points.
+
+use ark_ec::AffineRepr;
+use ark_serialize::CanonicalSerialize;
+use ark_std::{test_rng, vec::Vec};
+use sp_ark_models::deserialize::DeserializeBatch;
+
+use super::{outside_subgroup, points, MSM_LEN};
+
+/// Check the batched deserialization of `E`, given a point of G1 outside of its
+/// prime order subgroup.
+fn check_deserialize_batch<E: DeserializeBatch>(outside: E::G1Affine) {
+    let mut rng = test_rng();
+    let mut p = points::<E::G1>(&mut rng, MSM_LEN);
+    p.push(E::G1Affine::zero());
+    let q = points::<E::G2>(&mut rng, MSM_LEN);
+    let mut p_bytes = Vec::new();
+    p.serialize_compressed(&mut p_bytes).unwrap();
+    let mut q_bytes = Vec::new();
+    q.serialize_compressed(&mut q_bytes).unwrap();
+    assert_eq!(E::deserialize_g1_batch(&p_bytes).unwrap(), p);
+    assert_eq!(E::deserialize_g2_batch(&q_bytes).unwrap(), q);
+    assert!(E::deserialize_g2_batch(&q_bytes[..q_bytes.len() - 1]).is_err());
+
+    p.push(outside);
+    p_bytes.clear();
+    p.serialize_compressed(&mut p_bytes).unwrap();
+    assert!(E::deserialize_g1_batch(&p_bytes).is_err());
+}
+
+#[test]
+fn bls12_381() {
+    check_deserialize_batch::<sp_ark_bls12_381::Bls12_381<crate::bls12_381::Host>>(
+        outside_subgroup(),
+    );
+}
+
+#[test]
+fn bls12_377() {
+    check_deserialize_batch::<sp_ark_bls12_377::Bls12_377<crate::bls12_377::Host>>(
+        outside_subgroup(),
+    );
+}
+
+#[test]
+fn bw6_761() {
+    check_deserialize_batch::<sp_ark_bw6_761::BW6_761<crate::bw6_761::Host>>(outside_subgroup());
+}
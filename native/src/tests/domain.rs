@@ -0,0 +1,59 @@
+//! FFTs and batch inversions over the scalar fields.
+
+use ark_ff::FftField;
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_std::test_rng;
+use sp_ark_models::domain::{batch_inverse, Domain, FftHost};
+
+use super::{random, MSM_LEN};
+
+fn check_domain<F: FftField, H: FftHost<F>>() {
+    let mut rng = test_rng();
+    let coeffs = random::<F>(&mut rng, MSM_LEN);
+    for offset in [F::one(), F::GENERATOR] {
+        let domain = Domain::<F, H>::new(MSM_LEN)
+            .unwrap()
+            .get_coset(offset)
+            .unwrap();
+        let expected = Radix2EvaluationDomain::<F>::new_coset(MSM_LEN, offset).unwrap();
+
+        let mut padded = coeffs.clone();
+        padded.resize(domain.size(), F::zero());
+        let evals = expected.fft(&coeffs);
+        assert_eq!(H::fft(&padded, offset), Ok(evals.clone()));
+        assert_eq!(H::ifft(&evals, offset), Ok(padded.clone()));
+        assert_eq!(domain.host_fft(&coeffs), evals);
+        assert_eq!(domain.host_ifft(&evals), padded);
+        assert_eq!(domain.fft(&coeffs), evals);
+        assert_eq!(domain.ifft(&evals), padded);
+
+        let tau = F::rand(&mut rng);
+        assert_eq!(
+            domain.evaluate_all_lagrange_coefficients(tau),
+            expected.evaluate_all_lagrange_coefficients(tau)
+        );
+        let x = domain.element(3);
+        assert_eq!(
+            domain.evaluate_all_lagrange_coefficients(x),
+            expected.evaluate_all_lagrange_coefficients(x)
+        );
+    }
+
+    let mut values = coeffs;
+    values[2] = F::zero();
+    let mut expected = values.clone();
+    ark_ff::batch_inversion(&mut expected);
+    assert_eq!(H::batch_inverse(&values), Ok(expected.clone()));
+    batch_inverse::<F, H>(&mut values);
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn bls12_381() {
+    check_domain::<sp_ark_bls12_381::Fr, sp_ark_bls12_381::Config<crate::bls12_381::Host>>();
+}
+
+#[test]
+fn bls12_377() {
+    check_domain::<sp_ark_bls12_377::Fr, sp_ark_bls12_377::Config<crate::bls12_377::Host>>();
+}
@@ -0,0 +1,188 @@
+//! Reference vectors computed with c-kzg-4844 and the Ethereum mainnet trusted setup.
+
+use ark_std::{boxed::Box, vec::Vec};
+use sp_ark_bls12_381::eip4844::{
+    verify_blob_kzg_proof, verify_blob_kzg_proof_batch, verify_kzg_proof, Blob, Bytes32, Bytes48,
+    Error, Settings, BYTES_PER_BLOB,
+};
+
+use super::hex;
+
+type Host = crate::bls12_381::Host;
+
+/// `KZG_SETUP_G2_MONOMIAL[1]` of the mainnet trusted setup.
+const TAU_G2: &str = "b5bfd7dd8cdeb128843bc287230af38926187075cbfbefa81009a2ce615ac53d2914e5870cb452d2afaaab24f3499f72185cbfee53492714734429b7b38608e23926c911cceceac9a36851477ba4c60b087041de621000edc98edada20c1def2";
+/// Commitments and blob proofs of `blob(0)`, `blob(1)` and `blob(2)`.
+const COMMITMENTS: [&str; 3] = [
+    "a1169d8cbb878075924886a55987a6092f19e35c6151e7b5a3a654350e88610ef50b8715ead92ace49ed342e700374a4",
+    "81d5440476c071d7eb506d1ef78355ed67a076a5c82a75342d164972f9233afe05c179fe7c1f8a601787bc5de473650a",
+    "8a5b278cfb81c11b3153f3b713a9b05ff866ec63d1a090c646aed936db9373cb3c8c43252e50895edb2db07fe41f74a7",
+];
+const BLOB_PROOFS: [&str; 3] = [
+    "96cab6d5167697511fdcd56cfb82ac8176c53798aaca7c770f9f3276381461b2c799bb4842bdecf8c3511934b7532fbf",
+    "8525957aab243b75faff7ca6f4195532488c18cd7def788ab37a0a6f9bef614940cf31b4342f33bdc923c19a3e0f9089",
+    "b9b889892114b71776458de3753c583edce85344ec87f57430cbb0d36277b1e804405cb86039904b0ac8ff37a2a983de",
+];
+/// Evaluation of the polynomial of `blob(0)` at `0x1010…10` and its proof.
+const Z: &str = "1010101010101010101010101010101010101010101010101010101010101010";
+const Y: &str = "10b48ae654febc57ab37c6967c6747498c00b4e2bc81c6305442fabef0c6a86f";
+const PROOF: &str = "a7a32f2bf47053871384a55d2cff731a107455817026bb58f4fe7271e4a5b70e35cbe3a75d120431a6a0c47835056d15";
+/// Evaluation of the polynomial of `blob(0)` at the root of unity `1`, its first
+/// element, and its proof.
+const Y_AT_ONE: &str = "00070e151c232a31383f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9";
+const PROOF_AT_ONE: &str = "b95bf19d3afe1f56d46c55866bf5d02f0fb12b095996dc79ff1b180f2f627995b3405ef86eefa081f80a75b982d94a96";
+
+/// The scalar field modulus, the smallest non-canonical field element.
+const MODULUS: &str = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+/// Compressed point with `x = 1`, which is not on the curve.
+const NOT_ON_CURVE: &str = "800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+/// Compressed point with `x = 0`, on the curve but outside the prime order subgroup.
+const NOT_IN_SUBGROUP: &str = "800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+const INFINITY: &str = "c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+fn settings() -> Settings<Host> {
+    Settings::from_bytes(&hex(TAU_G2)).unwrap()
+}
+
+/// Blob whose elements have a zero first byte, keeping them canonical.
+fn blob(seed: u8) -> Box<Blob> {
+    let mut blob = Box::new([0; BYTES_PER_BLOB]);
+    for (i, element) in blob.chunks_exact_mut(32).enumerate() {
+        for (j, byte) in element.iter_mut().enumerate().skip(1) {
+            *byte = ((i * 31 + j * 7) as u8) ^ seed;
+        }
+    }
+    blob
+}
+
+#[test]
+fn verify_kzg_proof_vectors() {
+    let settings = settings();
+    let commitment: Bytes48 = hex(COMMITMENTS[0]);
+    let z: Bytes32 = hex(Z);
+    let y: Bytes32 = hex(Y);
+    let proof: Bytes48 = hex(PROOF);
+    let mut one = [0; 32];
+    one[31] = 1;
+
+    assert_eq!(
+        verify_kzg_proof(&settings, &commitment, &z, &y, &proof),
+        Ok(true)
+    );
+    assert_eq!(
+        verify_kzg_proof(
+            &settings,
+            &commitment,
+            &one,
+            &hex(Y_AT_ONE),
+            &hex(PROOF_AT_ONE)
+        ),
+        Ok(true)
+    );
+    // The zero polynomial evaluates to zero everywhere, with the identity as proof.
+    assert_eq!(
+        verify_kzg_proof(&settings, &hex(INFINITY), &z, &[0; 32], &hex(INFINITY)),
+        Ok(true)
+    );
+
+    // Incorrect evaluation or proof.
+    assert_eq!(
+        verify_kzg_proof(&settings, &commitment, &z, &one, &proof),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_kzg_proof(&settings, &commitment, &z, &y, &hex(PROOF_AT_ONE)),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_kzg_proof(&settings, &hex(COMMITMENTS[1]), &z, &y, &proof),
+        Ok(false)
+    );
+
+    // Non-canonical field elements.
+    assert_eq!(
+        verify_kzg_proof(&settings, &commitment, &hex(MODULUS), &y, &proof),
+        Err(Error::InvalidFieldElement)
+    );
+    assert_eq!(
+        verify_kzg_proof(&settings, &commitment, &z, &[0xff; 32], &proof),
+        Err(Error::InvalidFieldElement)
+    );
+
+    // Invalid points.
+    for invalid in [NOT_ON_CURVE, NOT_IN_SUBGROUP] {
+        assert_eq!(
+            verify_kzg_proof(&settings, &hex(invalid), &z, &y, &proof),
+            Err(Error::InvalidPoint)
+        );
+        assert_eq!(
+            verify_kzg_proof(&settings, &commitment, &z, &y, &hex(invalid)),
+            Err(Error::InvalidPoint)
+        );
+    }
+}
+
+#[test]
+fn verify_blob_kzg_proof_vectors() {
+    let settings = settings();
+    for (seed, (commitment, proof)) in COMMITMENTS.iter().zip(BLOB_PROOFS).enumerate() {
+        assert_eq!(
+            verify_blob_kzg_proof(&settings, &blob(seed as u8), &hex(commitment), &hex(proof)),
+            Ok(true)
+        );
+    }
+
+    let blob = blob(0);
+    let commitment: Bytes48 = hex(COMMITMENTS[0]);
+    assert_eq!(
+        verify_blob_kzg_proof(&settings, &blob, &commitment, &hex(BLOB_PROOFS[1])),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof(&settings, &blob, &commitment, &hex(NOT_IN_SUBGROUP)),
+        Err(Error::InvalidPoint)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof(&settings, &blob, &hex(NOT_ON_CURVE), &hex(BLOB_PROOFS[0])),
+        Err(Error::InvalidPoint)
+    );
+
+    let mut non_canonical = blob.clone();
+    non_canonical[..32].copy_from_slice(&hex::<32>(MODULUS));
+    assert_eq!(
+        verify_blob_kzg_proof(&settings, &non_canonical, &commitment, &hex(BLOB_PROOFS[0])),
+        Err(Error::InvalidFieldElement)
+    );
+}
+
+#[test]
+fn verify_blob_kzg_proof_batch_vectors() {
+    let settings = settings();
+    let blobs = (0..3).map(|seed| *blob(seed)).collect::<Vec<_>>();
+    let commitments = COMMITMENTS.map(hex);
+    let mut proofs = BLOB_PROOFS.map(hex);
+
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&settings, &blobs, &commitments, &proofs),
+        Ok(true)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&settings, &[], &[], &[]),
+        Ok(true)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&settings, &blobs[..2], &commitments, &proofs),
+        Err(Error::LengthMismatch)
+    );
+
+    proofs.swap(1, 2);
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&settings, &blobs, &commitments, &proofs),
+        Ok(false)
+    );
+    proofs[1] = hex(NOT_ON_CURVE);
+    assert_eq!(
+        verify_blob_kzg_proof_batch(&settings, &blobs, &commitments, &proofs),
+        Err(Error::InvalidPoint)
+    );
+}
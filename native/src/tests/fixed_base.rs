@@ -0,0 +1,91 @@
+//! Fixed-base precomputed tables.
+
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{test_rng, vec::Vec, One, Zero};
+use sp_ark_models::fixed_base::FixedBase;
+
+use super::{random, MSM_LEN};
+
+fn check_fixed_base<G: CurveGroup>() {
+    let mut rng = test_rng();
+    let one = G::ScalarField::one();
+    let mut scalars = random::<G::ScalarField>(&mut rng, MSM_LEN);
+    scalars.extend([G::ScalarField::zero(), one, -one]);
+    let table = FixedBase::<G>::generator();
+    assert_eq!(table.base(), G::generator().into_affine());
+    for s in &scalars {
+        assert_eq!(table.mul(s), G::generator() * s);
+    }
+    assert_eq!(
+        table.batch_mul(&scalars),
+        G::normalize_batch(
+            &scalars
+                .iter()
+                .map(|s| G::generator() * s)
+                .collect::<Vec<_>>()
+        )
+    );
+
+    let base = G::rand(&mut rng);
+    for window in [1, 5] {
+        let table = FixedBase::with_window(base, window);
+        assert_eq!(table.mul(&scalars[0]), base * scalars[0]);
+    }
+
+    let mut bytes = Vec::new();
+    table.serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(
+        FixedBase::<G>::deserialize_compressed(&bytes[..]).unwrap(),
+        table
+    );
+    // The window size, then the length prefixes of the table and of its first row.
+    base.into_affine()
+        .serialize_compressed(&mut bytes[1 + 8 + 8..])
+        .unwrap();
+    assert!(matches!(
+        FixedBase::<G>::deserialize_compressed(&bytes[..]),
+        Err(SerializationError::InvalidData)
+    ));
+    assert!(FixedBase::<G>::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+    bytes[0] = 5;
+    assert!(matches!(
+        FixedBase::<G>::deserialize_compressed_unchecked(&bytes[..]),
+        Err(SerializationError::InvalidData)
+    ));
+}
+
+#[test]
+fn bls12_381() {
+    type Host = crate::bls12_381::Host;
+    check_fixed_base::<sp_ark_bls12_381::G1Projective<Host>>();
+    check_fixed_base::<sp_ark_bls12_381::G2Projective<Host>>();
+}
+
+#[test]
+fn bls12_377() {
+    type Host = crate::bls12_377::Host;
+    check_fixed_base::<sp_ark_bls12_377::G1Projective<Host>>();
+    check_fixed_base::<sp_ark_bls12_377::G2Projective<Host>>();
+}
+
+#[test]
+fn bw6_761() {
+    type Host = crate::bw6_761::Host;
+    check_fixed_base::<sp_ark_bw6_761::G1Projective<Host>>();
+    check_fixed_base::<sp_ark_bw6_761::G2Projective<Host>>();
+}
+
+#[test]
+fn ed_on_bls12_377() {
+    check_fixed_base::<sp_ark_ed_on_bls12_377::EdwardsProjective<crate::ed_on_bls12_377::Host>>();
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    check_fixed_base::<
+        sp_ark_ed_on_bls12_381_bandersnatch::EdwardsProjective<
+            crate::ed_on_bls12_381_bandersnatch::Host,
+        >,
+    >();
+}
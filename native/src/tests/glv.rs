@@ -0,0 +1,120 @@
+//! GLV scalar multiplication.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{One, Zero};
+use ark_std::{test_rng, vec::Vec, UniformRand};
+use sp_ark_models::glv::{decompose, GlvConfig};
+
+use super::{random, MSM_LEN};
+
+fn check_glv<G: CurveGroup>(glv_mul: impl Fn(&G, &G::ScalarField) -> G)
+where
+    G::Config: GlvConfig,
+{
+    let mut rng = test_rng();
+    let lambda = <G::Config as GlvConfig>::LAMBDA;
+    let one = G::ScalarField::one();
+    let mut scalars = random::<G::ScalarField>(&mut rng, MSM_LEN);
+    scalars.extend([G::ScalarField::zero(), one, -one, lambda, -lambda]);
+    for k in scalars {
+        let [(neg1, k1), (neg2, k2)] = decompose::<G::Config>(&k);
+        assert!(k1 >> 127 == 0 && k2 >> 127 == 0);
+        let signed = |negative: bool, k: u128| {
+            let k = G::ScalarField::from(k);
+            if negative {
+                -k
+            } else {
+                k
+            }
+        };
+        assert_eq!(signed(neg1, k1) + lambda * signed(neg2, k2), k);
+
+        let base = G::rand(&mut rng);
+        assert_eq!(glv_mul(&base, &k), base * k);
+    }
+    assert_eq!(glv_mul(&G::zero(), &lambda), G::zero());
+}
+
+/// Check that the point `q` of a host-shaped curve, equal to the point `p` of the
+/// upstream curve, is multiplied as upstream.
+pub(super) fn check_mul_matches_arkworks<A, N>(q: A, p: N)
+where
+    A: AffineRepr,
+    N: AffineRepr<BaseField = A::BaseField, ScalarField = A::ScalarField>,
+{
+    let scalar = A::ScalarField::rand(&mut test_rng());
+    // Larger than the group order, as `mul_bigint` allows.
+    let limbs = [u64::MAX; 5];
+    assert_eq!(
+        (q * scalar).into_affine().xy(),
+        (p * scalar).into_affine().xy()
+    );
+    assert_eq!(
+        q.mul_bigint(limbs).into_affine().xy(),
+        p.mul_bigint(limbs).into_affine().xy()
+    );
+}
+
+#[test]
+fn bls12_381() {
+    check_glv(sp_ark_bls12_381::g1::Config::<crate::bls12_381::Host>::glv_mul);
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    use sp_ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig;
+
+    type Host = crate::ed_on_bls12_381_bandersnatch::Host;
+    check_glv(BandersnatchConfig::<Host>::glv_mul_sw);
+    check_glv(BandersnatchConfig::<Host>::glv_mul_te);
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch_fallback() {
+    use ark_ed_on_bls12_381_bandersnatch as native;
+    use sp_ark_ed_on_bls12_381_bandersnatch::{
+        EdwardsAffine, Fallback, Fq, HostFunctions, SWAffine,
+    };
+
+    struct Unavailable;
+
+    impl HostFunctions for Unavailable {
+        fn ed_on_bls12_381_bandersnatch_te_msm(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+            Err(())
+        }
+        fn ed_on_bls12_381_bandersnatch_sw_msm(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+            Err(())
+        }
+        fn ed_on_bls12_381_bandersnatch_sw_mul_projective(
+            _: Vec<u8>,
+            _: Vec<u8>,
+        ) -> Result<Vec<u8>, ()> {
+            Err(())
+        }
+        fn ed_on_bls12_381_bandersnatch_te_mul_projective(
+            _: Vec<u8>,
+            _: Vec<u8>,
+        ) -> Result<Vec<u8>, ()> {
+            Err(())
+        }
+    }
+
+    type Host = Fallback<Unavailable>;
+
+    // Points inside and outside of the prime order subgroup, which the software
+    // multiplication splits into a multiple of the cofactor and a remainder.
+    let mut in_subgroup = [0, 0];
+    for y in 1u64..16 {
+        if let Some(p) = native::EdwardsAffine::get_point_from_y_unchecked(Fq::from(y), false) {
+            in_subgroup[p.is_in_correct_subgroup_assuming_on_curve() as usize] += 1;
+            check_mul_matches_arkworks(EdwardsAffine::<Host>::new_unchecked(p.x, p.y), p);
+        }
+    }
+    for x in 1u64..16 {
+        if let Some(p) = native::SWAffine::get_point_from_x_unchecked(Fq::from(x), false) {
+            in_subgroup[p.is_in_correct_subgroup_assuming_on_curve() as usize] += 1;
+            check_mul_matches_arkworks(SWAffine::<Host>::new_unchecked(p.x, p.y), p);
+        }
+    }
+    assert!(in_subgroup[0] > 0 && in_subgroup[1] > 0);
+}
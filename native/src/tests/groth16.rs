@@ -0,0 +1,168 @@
+//! Groth16 verification.
+
+use ark_ec::{CurveGroup, Group};
+use ark_ff::{Field, One};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{test_rng, vec::Vec, UniformRand};
+use sp_ark_models::{
+    deserialize::DeserializeBatch,
+    groth16::{prepare_verifying_key, verify_proof, Proof, VerifyingKey},
+    HostCallError, PairingCheck, TryMsm,
+};
+
+use super::random;
+
+/// Check the verification of a synthetic proof from random trapdoors, satisfying
+/// `a * b = alpha * beta + inputs * gamma + c * delta`.
+fn check_groth16<E: PairingCheck + DeserializeBatch>()
+where
+    E::G1: TryMsm,
+{
+    let mut rng = test_rng();
+    let alpha = E::ScalarField::rand(&mut rng);
+    let beta = E::ScalarField::rand(&mut rng);
+    let gamma = E::ScalarField::rand(&mut rng);
+    let delta = E::ScalarField::rand(&mut rng);
+    let inputs = random::<E::ScalarField>(&mut rng, 3);
+    let abc = random::<E::ScalarField>(&mut rng, inputs.len() + 1);
+    let gamma_inv = gamma.inverse().unwrap();
+
+    let vk = VerifyingKey::<E> {
+        alpha_g1: (E::G1::generator() * alpha).into_affine(),
+        beta_g2: (E::G2::generator() * beta).into_affine(),
+        gamma_g2: (E::G2::generator() * gamma).into_affine(),
+        delta_g2: (E::G2::generator() * delta).into_affine(),
+        gamma_abc_g1: abc
+            .iter()
+            .map(|s| (E::G1::generator() * (*s * gamma_inv)).into_affine())
+            .collect(),
+    };
+    let l = abc[0]
+        + inputs
+            .iter()
+            .zip(&abc[1..])
+            .map(|(x, s)| *x * s)
+            .sum::<E::ScalarField>();
+    let a = E::ScalarField::rand(&mut rng);
+    let c = E::ScalarField::rand(&mut rng);
+    let b = (alpha * beta + l + c * delta) * a.inverse().unwrap();
+    let proof = Proof::<E> {
+        a: (E::G1::generator() * a).into_affine(),
+        b: (E::G2::generator() * b).into_affine(),
+        c: (E::G1::generator() * c).into_affine(),
+    };
+
+    let mut vk_bytes = Vec::new();
+    vk.serialize_compressed(&mut vk_bytes).unwrap();
+    let mut proof_bytes = Vec::new();
+    proof.serialize_compressed(&mut proof_bytes).unwrap();
+    let vk = VerifyingKey::<E>::deserialize_compressed(&vk_bytes[..]).unwrap();
+    assert_eq!(
+        VerifyingKey::<E>::deserialize_batch(&vk_bytes[..]).unwrap(),
+        vk
+    );
+    assert_eq!(
+        Proof::<E>::deserialize_compressed(&proof_bytes[..]).unwrap(),
+        proof
+    );
+
+    let pvk = prepare_verifying_key(vk);
+    assert_eq!(verify_proof(&pvk, &proof, &inputs), Ok(true));
+
+    let mut wrong_inputs = inputs.clone();
+    wrong_inputs[0] += E::ScalarField::one();
+    assert_eq!(verify_proof(&pvk, &proof, &wrong_inputs), Ok(false));
+
+    let wrong_proof = Proof::<E> {
+        c: proof.a,
+        ..proof.clone()
+    };
+    assert_eq!(verify_proof(&pvk, &wrong_proof, &inputs), Ok(false));
+
+    assert_eq!(
+        verify_proof(&pvk, &proof, &inputs[1..]),
+        Err(HostCallError::LengthMismatch)
+    );
+}
+
+#[test]
+fn bls12_381() {
+    check_groth16::<sp_ark_bls12_381::Bls12_381<crate::bls12_381::Host>>();
+}
+
+#[test]
+fn bls12_377() {
+    check_groth16::<sp_ark_bls12_377::Bls12_377<crate::bls12_377::Host>>();
+}
+
+#[test]
+fn bw6_761() {
+    check_groth16::<sp_ark_bw6_761::BW6_761<crate::bw6_761::Host>>();
+}
+
+/// Proof of knowledge of `x` and `y` with `x * y = z`, `z` being the public input.
+struct Product {
+    x: Option<ark_bls12_381::Fr>,
+    y: Option<ark_bls12_381::Fr>,
+    z: Option<ark_bls12_381::Fr>,
+}
+
+impl ark_relations::r1cs::ConstraintSynthesizer<ark_bls12_381::Fr> for Product {
+    fn generate_constraints(
+        self,
+        cs: ark_relations::r1cs::ConstraintSystemRef<ark_bls12_381::Fr>,
+    ) -> Result<(), ark_relations::r1cs::SynthesisError> {
+        use ark_relations::{lc, r1cs::SynthesisError};
+
+        let z = cs.new_input_variable(|| self.z.ok_or(SynthesisError::AssignmentMissing))?;
+        let x = cs.new_witness_variable(|| self.x.ok_or(SynthesisError::AssignmentMissing))?;
+        let y = cs.new_witness_variable(|| self.y.ok_or(SynthesisError::AssignmentMissing))?;
+        cs.enforce_constraint(lc!() + x, lc!() + y, lc!() + z)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn verifies_ark_groth16_proofs() {
+    use ark_groth16::Groth16;
+    use ark_snark::SNARK;
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use sp_ark_bls12_381::{Bls12_381, Fr};
+
+    type Curve = Bls12_381<crate::bls12_381::Host>;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let x = Fr::rand(&mut rng);
+    let y = Fr::rand(&mut rng);
+    let z = x * y;
+    let (pk, vk) = Groth16::<ark_bls12_381::Bls12_381>::circuit_specific_setup(
+        Product {
+            x: None,
+            y: None,
+            z: None,
+        },
+        &mut rng,
+    )
+    .unwrap();
+    let proof = Groth16::<ark_bls12_381::Bls12_381>::prove(
+        &pk,
+        Product {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+        },
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut vk_bytes = Vec::new();
+    vk.serialize_compressed(&mut vk_bytes).unwrap();
+    let mut proof_bytes = Vec::new();
+    proof.serialize_compressed(&mut proof_bytes).unwrap();
+    let vk = VerifyingKey::<Curve>::deserialize_compressed(&vk_bytes[..]).unwrap();
+    let proof = Proof::<Curve>::deserialize_compressed(&proof_bytes[..]).unwrap();
+
+    let pvk = prepare_verifying_key(vk);
+    assert_eq!(verify_proof(&pvk, &proof, &[z]), Ok(true));
+    assert_eq!(verify_proof(&pvk, &proof, &[z + Fr::one()]), Ok(false));
+}
@@ -0,0 +1,299 @@
+//! Errors of the host calls, and the software fallbacks of the infallible operations.
+
+use ark_ec::{
+    pairing::{Pairing, PairingOutput},
+    AffineRepr, CurveGroup, Group, VariableBaseMSM,
+};
+use ark_ff::Field;
+use ark_serialize::CanonicalSerialize;
+use ark_std::{test_rng, vec::Vec, UniformRand};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use sp_ark_bls12_381::{
+    g1, Bls12_381, Config, Fallback, Fr, G1Affine, G1Projective, G2Affine, HostFunctions,
+};
+use sp_ark_models::{HostCallError, TryMsm};
+
+use super::glv::check_mul_matches_arkworks;
+
+struct Rejecting;
+
+impl HostFunctions for Rejecting {
+    fn bls12_381_multi_miller_loop(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_final_exponentiation(_: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_msm_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_msm_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_mul_projective_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+    fn bls12_381_mul_projective_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Err(())
+    }
+}
+
+struct Malformed;
+
+impl HostFunctions for Malformed {
+    fn bls12_381_multi_miller_loop(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Ok(Vec::new())
+    }
+    fn bls12_381_final_exponentiation(_: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Ok(Vec::new())
+    }
+    fn bls12_381_msm_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Ok(Vec::new())
+    }
+    fn bls12_381_msm_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Ok(Vec::new())
+    }
+    fn bls12_381_mul_projective_g1(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Ok(Vec::new())
+    }
+    fn bls12_381_mul_projective_g2(_: Vec<u8>, _: Vec<u8>) -> Result<Vec<u8>, ()> {
+        Ok(Vec::new())
+    }
+}
+
+#[test]
+fn host_rejected() {
+    let p = G1Affine::<Rejecting>::generator();
+    let q = G2Affine::<Rejecting>::generator();
+    assert_eq!(
+        Config::<Rejecting>::try_multi_miller_loop([p], [q]).unwrap_err(),
+        HostCallError::HostRejected
+    );
+    assert_eq!(
+        g1::Config::<Rejecting>::try_mul(&p.into_group(), &[2]).unwrap_err(),
+        HostCallError::HostRejected
+    );
+
+    // The target group operations are computed in software when unavailable.
+    let gt = PairingOutput::<Bls12_381<Rejecting>>(
+        PairingOutput::<ark_bls12_381::Bls12_381>::generator().0,
+    );
+    assert_eq!(
+        Config::<Rejecting>::try_gt_mul(&gt, &[2]).unwrap_err(),
+        HostCallError::HostRejected
+    );
+    assert_eq!(Bls12_381::<Rejecting>::gt_mul(&gt, Fr::from(2u64)), gt + gt);
+
+    // As are the FFTs and batch inversions.
+    use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+    let coeffs = [Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
+    let domain = sp_ark_bls12_381::domain::Domain::<Rejecting>::new(4).unwrap();
+    let expected = Radix2EvaluationDomain::<Fr>::new(4).unwrap().fft(&coeffs);
+    assert_eq!(domain.host_fft(&coeffs), expected);
+    let mut values = coeffs;
+    sp_ark_bls12_381::domain::batch_inverse::<Rejecting>(&mut values);
+    assert_eq!(values[1], Fr::from(2u64).inverse().unwrap());
+}
+
+#[test]
+fn fallback_mul_uses_glv() {
+    type Host = Fallback<Rejecting>;
+
+    let p = (ark_bls12_381::G1Projective::generator() * Fr::rand(&mut test_rng())).into_affine();
+    check_mul_matches_arkworks(G1Affine::<Host>::new_unchecked(p.x, p.y), p);
+}
+
+static MILLER_LOOPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Host without a pairing check, counting its Miller loops.
+struct WithoutPairingCheck;
+
+impl HostFunctions for WithoutPairingCheck {
+    fn bls12_381_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        MILLER_LOOPS.fetch_add(1, Ordering::Relaxed);
+        crate::bls12_381::multi_miller_loop(a, b)
+    }
+    fn bls12_381_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::final_exponentiation(f12)
+    }
+    fn bls12_381_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::msm_g1(bases, scalars)
+    }
+    fn bls12_381_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::msm_g2(bases, scalars)
+    }
+    fn bls12_381_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::mul_projective_g1(base, scalar)
+    }
+    fn bls12_381_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::mul_projective_g2(base, scalar)
+    }
+}
+
+#[test]
+fn pairing_check_uses_host_miller_loop() {
+    type Host = Fallback<WithoutPairingCheck>;
+    let p = G1Affine::<Host>::generator();
+    let q = G2Affine::<Host>::generator();
+
+    let before = MILLER_LOOPS.load(Ordering::Relaxed);
+    assert!(Bls12_381::<Host>::multi_pairing_is_one([p, -p], [q, q]));
+    assert!(!Bls12_381::<Host>::multi_pairing_is_one([p, p], [q, q]));
+    assert_eq!(MILLER_LOOPS.load(Ordering::Relaxed), before + 2);
+
+    // Inputs of different lengths never pair to one.
+    assert!(!Bls12_381::<Host>::multi_pairing_is_one([p, -p], [q]));
+}
+
+#[test]
+fn host_batch_without_batches() {
+    use sp_ark_bls12_381::batch::{HostBatch, Output};
+
+    // Without a batch host function, every operation is its own host call.
+    let p = G1Projective::<WithoutPairingCheck>::generator();
+    let s = Fr::from(3u64);
+    assert_eq!(
+        HostBatch::<WithoutPairingCheck>::new()
+            .mul_g1(&p, &s)
+            .msm_g1(&[p.into_affine()], &[s])
+            .unwrap()
+            .execute()
+            .unwrap(),
+        [Output::G1(p * s), Output::G1(p * s)]
+    );
+
+    // Which fail the whole batch when they fail.
+    assert_eq!(
+        HostBatch::<Rejecting>::new()
+            .mul_g1(&G1Projective::<Rejecting>::generator(), &s)
+            .execute()
+            .unwrap_err(),
+        HostCallError::HostRejected
+    );
+}
+
+#[test]
+fn infallible_operations_fall_back_to_software() {
+    type Host = crate::bls12_381::Host;
+    let p = G1Affine::<Rejecting>::generator();
+    let q = G2Affine::<Rejecting>::generator();
+    let expected = Bls12_381::<Host>::multi_miller_loop(
+        [G1Affine::<Host>::generator()],
+        [G2Affine::<Host>::generator()],
+    );
+    assert_eq!(
+        Bls12_381::<Rejecting>::multi_miller_loop([p], [q]).0,
+        expected.0
+    );
+
+    let scalars = [Fr::from(2u64)];
+    assert_eq!(
+        <G1Projective<Rejecting> as TryMsm>::try_msm(&[p], &scalars).unwrap_err(),
+        HostCallError::HostRejected
+    );
+    assert_eq!(
+        G1Projective::<Rejecting>::msm(&[p], &scalars).unwrap(),
+        p + p
+    );
+    assert_eq!(p * scalars[0], p + p);
+    assert_eq!(q.into_group() * scalars[0], q + q);
+}
+
+#[test]
+fn errors_reach_generic_verifiers() {
+    use sp_ark_models::groth16::{prepare_inputs, prepare_verifying_key, VerifyingKey};
+
+    let p = G1Affine::<Malformed>::generator();
+    let q = G2Affine::<Malformed>::generator();
+    let pvk = prepare_verifying_key::<Bls12_381<Malformed>>(VerifyingKey {
+        alpha_g1: p,
+        beta_g2: q,
+        gamma_g2: q,
+        delta_g2: q,
+        gamma_abc_g1: vec![p, p],
+    });
+    assert_eq!(
+        prepare_inputs(&pvk, &[Fr::from(2u64)]).unwrap_err(),
+        HostCallError::DecodeFailed
+    );
+}
+
+#[test]
+fn decode_failed() {
+    let p = G1Affine::<Malformed>::generator();
+    let q = G2Affine::<Malformed>::generator();
+    assert_eq!(
+        Config::<Malformed>::try_multi_miller_loop([p], [q]).unwrap_err(),
+        HostCallError::DecodeFailed
+    );
+    assert_eq!(
+        g1::Config::<Malformed>::try_msm(&[p], &[Fr::from(2u64)]).unwrap_err(),
+        HostCallError::DecodeFailed
+    );
+}
+
+#[test]
+fn length_mismatch() {
+    type Host = crate::bls12_381::Host;
+    let p = G1Affine::<Host>::generator();
+    let q = G2Affine::<Host>::generator();
+    assert_eq!(
+        Config::<Host>::try_multi_miller_loop([p, p], [q]).unwrap_err(),
+        HostCallError::LengthMismatch
+    );
+    assert_eq!(
+        g1::Config::<Host>::try_msm(&[p, p], &[Fr::from(2u64)]).unwrap_err(),
+        HostCallError::LengthMismatch
+    );
+    let gt = PairingOutput::<Bls12_381<Host>>::generator();
+    assert_eq!(
+        Config::<Host>::try_gt_msm(&[gt, gt], &[Fr::from(2u64)]).unwrap_err(),
+        HostCallError::LengthMismatch
+    );
+}
+
+/// Host deserializing every vector of points of G1 as the empty vector.
+struct DroppingPoints;
+
+impl HostFunctions for DroppingPoints {
+    fn bls12_381_multi_miller_loop(a: Vec<u8>, b: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::multi_miller_loop(a, b)
+    }
+    fn bls12_381_final_exponentiation(f12: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::final_exponentiation(f12)
+    }
+    fn bls12_381_msm_g1(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::msm_g1(bases, scalars)
+    }
+    fn bls12_381_msm_g2(bases: Vec<u8>, scalars: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::msm_g2(bases, scalars)
+    }
+    fn bls12_381_mul_projective_g1(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::mul_projective_g1(base, scalar)
+    }
+    fn bls12_381_mul_projective_g2(base: Vec<u8>, scalar: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::mul_projective_g2(base, scalar)
+    }
+    fn bls12_381_deserialize_g1(_: Vec<u8>) -> Result<Vec<u8>, ()> {
+        crate::bls12_381::deserialize_g1(0u64.to_le_bytes().to_vec())
+    }
+}
+
+#[test]
+fn deserialized_points_are_counted() {
+    use sp_ark_models::deserialize::DeserializeBatch;
+
+    let points = [G1Affine::<DroppingPoints>::generator(); 2];
+    let mut bytes = Vec::new();
+    points.to_vec().serialize_compressed(&mut bytes).unwrap();
+
+    assert_eq!(
+        Config::<DroppingPoints>::try_deserialize_g1_batch(&bytes).unwrap_err(),
+        HostCallError::DecodeFailed
+    );
+    // The infallible version deserializes them in WASM instead.
+    assert_eq!(
+        Bls12_381::<DroppingPoints>::deserialize_g1_batch(&bytes).unwrap(),
+        points
+    );
+}
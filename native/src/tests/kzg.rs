@@ -0,0 +1,117 @@
+//! KZG opening verification.
+
+use ark_ec::{CurveGroup, Group};
+use ark_ff::{Field, One, Zero};
+use ark_std::{test_rng, vec::Vec, UniformRand};
+use sp_ark_models::{
+    kzg::{verify, verify_batch, verify_multi, Opening, VerifierKey},
+    HostCallError, PairingCheck, TryMsm,
+};
+
+use super::random;
+
+fn check_kzg<E: PairingCheck>()
+where
+    E::G1: TryMsm,
+    E::G2: TryMsm,
+{
+    let mut rng = test_rng();
+    let tau = E::ScalarField::rand(&mut rng);
+    let powers = (0..4).map(|i| tau.pow([i as u64])).collect::<Vec<_>>();
+    let vk = VerifierKey::<E> {
+        powers_of_g1: powers[..3]
+            .iter()
+            .map(|s| (E::G1::generator() * s).into_affine())
+            .collect(),
+        powers_of_g2: powers
+            .iter()
+            .map(|s| (E::G2::generator() * s).into_affine())
+            .collect(),
+    };
+    let coeffs = random::<E::ScalarField>(&mut rng, 5);
+    let eval = |x: E::ScalarField| {
+        coeffs
+            .iter()
+            .rev()
+            .fold(E::ScalarField::zero(), |acc, c| acc * x + c)
+    };
+    let commitment = (E::G1::generator() * eval(tau)).into_affine();
+
+    let openings = (0..3)
+        .map(|_| {
+            let point = E::ScalarField::rand(&mut rng);
+            let value = eval(point);
+            let quotient = (eval(tau) - value) * (tau - point).inverse().unwrap();
+            Opening::<E> {
+                commitment,
+                point,
+                value,
+                proof: (E::G1::generator() * quotient).into_affine(),
+            }
+        })
+        .collect::<Vec<_>>();
+    for o in &openings {
+        assert_eq!(
+            verify(&vk, &o.commitment, o.point, o.value, &o.proof),
+            Ok(true)
+        );
+        let wrong_value = o.value + E::ScalarField::one();
+        assert_eq!(
+            verify(&vk, &o.commitment, o.point, wrong_value, &o.proof),
+            Ok(false)
+        );
+    }
+    assert_eq!(verify_batch(&vk, &openings, &mut rng), Ok(true));
+    let mut wrong_openings = openings.clone();
+    wrong_openings[1].proof = openings[0].proof;
+    assert_eq!(verify_batch(&vk, &wrong_openings, &mut rng), Ok(false));
+
+    let points = openings.iter().map(|o| o.point).collect::<Vec<_>>();
+    let values = openings.iter().map(|o| o.value).collect::<Vec<_>>();
+    let interpolation = points
+        .iter()
+        .zip(&values)
+        .map(|(zi, yi)| {
+            points.iter().filter(|zj| *zj != zi).fold(*yi, |acc, zj| {
+                acc * (tau - zj) * (*zi - zj).inverse().unwrap()
+            })
+        })
+        .sum::<E::ScalarField>();
+    let vanishing = points.iter().map(|z| tau - z).product::<E::ScalarField>();
+    let quotient = (eval(tau) - interpolation) * vanishing.inverse().unwrap();
+    let proof = (E::G1::generator() * quotient).into_affine();
+    assert_eq!(
+        verify_multi(&vk, &commitment, &points, &values, &proof),
+        Ok(true)
+    );
+    let mut wrong_values = values.clone();
+    wrong_values[2] += E::ScalarField::one();
+    assert_eq!(
+        verify_multi(&vk, &commitment, &points, &wrong_values, &proof),
+        Ok(false)
+    );
+    let repeated = [points[0], points[0], points[1]];
+    assert_eq!(
+        verify_multi(&vk, &commitment, &repeated, &values, &proof),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_multi(&vk, &commitment, &points, &values[1..], &proof),
+        Err(HostCallError::LengthMismatch)
+    );
+}
+
+#[test]
+fn bls12_381() {
+    check_kzg::<sp_ark_bls12_381::Bls12_381<crate::bls12_381::Host>>();
+}
+
+#[test]
+fn bls12_377() {
+    check_kzg::<sp_ark_bls12_377::Bls12_377<crate::bls12_377::Host>>();
+}
+
+#[test]
+fn bw6_761() {
+    check_kzg::<sp_ark_bw6_761::BW6_761<crate::bw6_761::Host>>();
+}
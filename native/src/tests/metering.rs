@@ -0,0 +1,164 @@
+//! Host call usage recorded by the metered hosts.
+
+use ark_ec::{pairing::Pairing, VariableBaseMSM};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{test_rng, UniformRand};
+use sp_ark_bls12_381::{Bls12_381, G1Projective, G2Projective, Metered};
+use sp_ark_models::metering::Operation;
+
+use super::{points, random, MSM_LEN};
+
+type Host = Metered<crate::bls12_381::Host>;
+
+#[test]
+fn usage_is_recorded() {
+    let mut rng = test_rng();
+    let mml = Host::usage(Operation::MultiMillerLoop);
+    let fe = Host::usage(Operation::FinalExponentiation);
+    let msm = Host::usage(Operation::MsmG1);
+
+    let a = random::<G1Projective<Host>>(&mut rng, 2);
+    let b = random::<G2Projective<Host>>(&mut rng, 2);
+    let _ = Bls12_381::<Host>::multi_pairing(a, b);
+
+    let bases = points::<G1Projective<Host>>(&mut rng, MSM_LEN);
+    let scalars = random::<sp_ark_bls12_381::Fr>(&mut rng, MSM_LEN);
+    let _ = G1Projective::<Host>::msm(&bases, &scalars).unwrap();
+
+    let mml = Host::usage(Operation::MultiMillerLoop).since(mml);
+    assert_eq!(mml.calls, 1);
+    assert_eq!(mml.args, 2);
+    assert!(mml.input_bytes > 0 && mml.output_bytes > 0);
+
+    let fe = Host::usage(Operation::FinalExponentiation).since(fe);
+    assert_eq!(fe.calls, 1);
+    assert_eq!(fe.args, 1);
+
+    let msm = Host::usage(Operation::MsmG1).since(msm);
+    assert_eq!(msm.calls, 1);
+    assert_eq!(msm.args, MSM_LEN as u64);
+}
+
+#[test]
+fn usage_is_recorded_per_host() {
+    use sp_ark_bls12_381::Fallback;
+
+    // Hosts no other test meters.
+    type Used = Metered<Fallback<crate::bls12_381::Host>>;
+    type Unused = Metered<Fallback<Fallback<crate::bls12_381::Host>>>;
+
+    let mut rng = test_rng();
+    let p = G2Projective::<Used>::rand(&mut rng);
+    let used = Used::usage(Operation::MulProjectiveG2);
+    let unused = Unused::usage(Operation::MulProjectiveG2);
+
+    let _ = p * sp_ark_bls12_381::Fr::rand(&mut rng);
+
+    assert_eq!(Used::usage(Operation::MulProjectiveG2).since(used).calls, 1);
+    assert_eq!(Unused::usage(Operation::MulProjectiveG2), unused);
+}
+
+#[test]
+fn registered_bases_send_only_scalars() {
+    use sp_ark_bls12_381::registry::RegisteredBases;
+
+    let mut rng = test_rng();
+    let register = Host::usage(Operation::RegisterBases);
+    let msm = Host::usage(Operation::MsmWithHandle);
+
+    let bases = points::<G1Projective<Host>>(&mut rng, MSM_LEN);
+    let scalars = random::<sp_ark_bls12_381::Fr>(&mut rng, MSM_LEN);
+    let registered = RegisteredBases::<Host>::new(bases);
+    for _ in 0..3 {
+        let _ = registered.msm(&scalars).unwrap();
+    }
+
+    let register = Host::usage(Operation::RegisterBases).since(register);
+    assert_eq!(register.calls, 1);
+    assert_eq!(register.args, MSM_LEN as u64);
+
+    let msm = Host::usage(Operation::MsmWithHandle).since(msm);
+    assert_eq!(msm.calls, 3);
+    assert_eq!(msm.args, 3 * MSM_LEN as u64);
+    // Each call sends the handle and the length prefixed scalars.
+    assert_eq!(msm.input_bytes, 3 * (32 + 8 + 32 * MSM_LEN as u64));
+    assert!(register.input_bytes > 96 * MSM_LEN as u64);
+}
+
+#[test]
+fn small_msm_sends_limbs() {
+    use sp_ark_bls12_381::g1;
+    use sp_ark_models::small_msm::SmallMsm;
+
+    let mut rng = test_rng();
+    let usage = Host::usage(Operation::MsmSmall);
+
+    let bases = points::<G1Projective<Host>>(&mut rng, MSM_LEN);
+    let scalars = random::<u64>(&mut rng, MSM_LEN);
+    let _ = g1::Config::<Host>::msm_u64(&bases, &scalars).unwrap();
+
+    let usage = Host::usage(Operation::MsmSmall).since(usage);
+    assert_eq!(usage.calls, 1);
+    assert_eq!(usage.args, MSM_LEN as u64);
+}
+
+#[test]
+fn host_batch_is_one_call() {
+    use sp_ark_bls12_381::{batch::HostBatch, Fr};
+
+    let mut rng = test_rng();
+    let points = random::<G1Projective<Host>>(&mut rng, 3);
+    let usage = Host::usage(Operation::Batch);
+
+    let mut batch = HostBatch::<Host>::new();
+    for p in &points {
+        batch.mul_g1(p, &Fr::rand(&mut rng));
+    }
+    let _ = batch.execute().unwrap();
+
+    let usage = Host::usage(Operation::Batch).since(usage);
+    assert_eq!(usage.calls, 1);
+    assert_eq!(usage.args, 3);
+}
+
+#[test]
+fn projective_inputs_are_normalised_in_one_call() {
+    use sp_ark_bls12_377::{Bls12_377, G1Projective, G2Projective};
+
+    // No other test records normalisations into the BLS12-377 meter.
+    type Host = sp_ark_bls12_377::Metered<crate::bls12_377::Host>;
+
+    let mut rng = test_rng();
+    let a = random::<G1Projective<Host>>(&mut rng, 3);
+    let b = random::<G2Projective<Host>>(&mut rng, 3);
+
+    let usage = Host::usage(Operation::Normalize);
+    let _ = Bls12_377::<Host>::multi_miller_loop_projective(&a, &b);
+    let usage = Host::usage(Operation::Normalize).since(usage);
+    assert_eq!(usage.calls, 2);
+    assert_eq!(usage.args, 6);
+}
+
+#[test]
+fn compressed_points_are_deserialized_in_one_call() {
+    use sp_ark_bls12_377::{Bls12_377, G1Projective};
+    use sp_ark_models::deserialize::DeserializeBatch;
+
+    // No other test records deserializations into the BLS12-377 meter.
+    type Host = sp_ark_bls12_377::Metered<crate::bls12_377::Host>;
+
+    let mut rng = test_rng();
+    let points = points::<G1Projective<Host>>(&mut rng, MSM_LEN);
+    let mut bytes = Vec::new();
+    points.serialize_compressed(&mut bytes).unwrap();
+
+    let usage = Host::usage(Operation::Deserialize);
+    assert_eq!(
+        Bls12_377::<Host>::deserialize_g1_batch(&bytes).unwrap(),
+        points
+    );
+    let usage = Host::usage(Operation::Deserialize).since(usage);
+    assert_eq!(usage.calls, 1);
+    assert_eq!(usage.args, MSM_LEN as u64);
+    assert_eq!(usage.input_bytes, bytes.len() as u64);
+}
@@ -0,0 +1,54 @@
+//! Tests of the curve crates computing their host calls with the native hosts,
+//! one module per feature.
+
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    CurveGroup,
+};
+use ark_std::{rand::Rng, vec::Vec, UniformRand};
+
+mod arithmetic;
+mod batch;
+mod deserialize;
+mod domain;
+mod eip4844;
+mod fixed_base;
+mod glv;
+mod groth16;
+mod host_call_errors;
+mod kzg;
+mod metering;
+mod pairing;
+mod pedersen;
+mod registry;
+mod schnorr;
+mod small_msm;
+mod torus;
+mod vrf;
+
+const MSM_LEN: usize = 10;
+
+/// `len` random values, such as scalars.
+fn random<T: UniformRand>(rng: &mut impl Rng, len: usize) -> Vec<T> {
+    (0..len).map(|_| T::rand(rng)).collect()
+}
+
+/// `len` random points of `G`, in affine coordinates.
+fn points<G: CurveGroup>(rng: &mut impl Rng, len: usize) -> Vec<G::Affine> {
+    (0..len).map(|_| G::rand(rng).into_affine()).collect()
+}
+
+/// A point of the curve `P` outside of its prime order subgroup.
+fn outside_subgroup<P: SWCurveConfig>() -> Affine<P> {
+    (1u64..)
+        .filter_map(|x| Affine::<P>::get_point_from_x_unchecked(x.into(), false))
+        .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap()
+}
+
+/// Bytes of the hexadecimal string `hex`.
+fn hex<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0; N];
+    hex::decode_to_slice(hex, &mut bytes).unwrap();
+    bytes
+}
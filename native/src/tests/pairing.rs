@@ -0,0 +1,93 @@
+//! Pairings and Miller loops, compared with the upstream arkworks curves.
+
+use ark_ec::{
+    pairing::{Pairing, PairingOutput},
+    AffineRepr, CurveGroup, Group,
+};
+use ark_std::{test_rng, vec::Vec, UniformRand, Zero};
+use sp_ark_models::PairingCheck;
+
+use super::{random, MSM_LEN};
+
+fn check_pairing<E, N>()
+where
+    E: PairingCheck,
+    N: Pairing<ScalarField = E::ScalarField, TargetField = E::TargetField>,
+{
+    let mut rng = test_rng();
+    let a = E::ScalarField::rand(&mut rng);
+    let b = E::ScalarField::rand(&mut rng);
+
+    let result = E::pairing(E::G1::generator() * a, E::G2::generator() * b);
+    let expected = N::pairing(N::G1::generator() * a, N::G2::generator() * b);
+    assert_eq!(result.0, expected.0);
+
+    let p = [E::G1::generator() * a, -E::G1::generator() * (a * b)];
+    let q = [E::G2::generator() * b, E::G2::generator()];
+    assert!(E::multi_pairing_is_one(p, q));
+    let p = [E::G1::generator() * a, E::G1::generator() * (a * b)];
+    assert!(!E::multi_pairing_is_one(p, q));
+
+    // Upstream arkworks only pairs inputs of equal lengths.
+    let p = [E::G1::generator() * a, E::G1::generator() * b];
+    let q = [E::G2::generator() * b];
+    let result = E::multi_miller_loop(p, q);
+    let expected = N::multi_miller_loop([N::G1::generator() * a], [N::G2::generator() * b]);
+    assert_eq!(result.0, expected.0);
+}
+
+macro_rules! test_pairing {
+    ($name:ident, $sp:ident, $native:ident, $curve:ident) => {
+        mod $name {
+            use super::*;
+            use $native as native;
+
+            type Curve = $sp::$curve<crate::$name::Host>;
+            type G1 = <Curve as Pairing>::G1;
+            type G2 = <Curve as Pairing>::G2;
+            type Fr = <Curve as Pairing>::ScalarField;
+
+            #[test]
+            fn pairing_matches_arkworks() {
+                check_pairing::<Curve, native::$curve>();
+            }
+
+            #[test]
+            fn projective_inputs_are_normalised_at_once() {
+                let mut rng = test_rng();
+                let p = [G1::rand(&mut rng), G1::zero(), G1::rand(&mut rng)];
+                let q = [
+                    G2::rand(&mut rng),
+                    G2::rand(&mut rng),
+                    G2::rand(&mut rng).into_affine().into_group(),
+                ];
+                assert_eq!(
+                    Curve::multi_miller_loop_projective(&p, &q),
+                    Curve::multi_miller_loop(p, q)
+                );
+            }
+
+            #[test]
+            fn gt_mul_and_msm() {
+                let mut rng = test_rng();
+                let bases = (0..MSM_LEN)
+                    .map(|_| Curve::pairing(G1::rand(&mut rng), G2::rand(&mut rng)))
+                    .collect::<Vec<PairingOutput<Curve>>>();
+                let scalars = random::<Fr>(&mut rng, MSM_LEN);
+
+                assert_eq!(Curve::gt_mul(&bases[0], scalars[0]), bases[0] * scalars[0]);
+                let expected = bases
+                    .iter()
+                    .zip(&scalars)
+                    .map(|(base, scalar)| *base * scalar)
+                    .sum();
+                assert_eq!(Curve::gt_msm(&bases, &scalars), Ok(expected));
+                assert_eq!(Curve::gt_msm(&bases, &scalars[1..]), Err(MSM_LEN - 1));
+            }
+        }
+    };
+}
+
+test_pairing!(bls12_381, sp_ark_bls12_381, ark_bls12_381, Bls12_381);
+test_pairing!(bls12_377, sp_ark_bls12_377, ark_bls12_377, Bls12_377);
+test_pairing!(bw6_761, sp_ark_bw6_761, ark_bw6_761, BW6_761);
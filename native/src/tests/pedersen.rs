@@ -0,0 +1,83 @@
+//! Pedersen vector commitments.
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+use ark_std::{test_rng, vec::Vec, One, UniformRand};
+use sp_ark_models::{
+    pedersen::{commit, verify_opening, Generators},
+    HostCallError, TryMsm,
+};
+
+use super::{random, MSM_LEN};
+
+/// Check the commitments to vectors of `MSM_LEN` scalars, under `generators` of the
+/// `test domain`.
+fn check_pedersen<G: CurveGroup + TryMsm>(generators: &Generators<G>) {
+    assert_eq!(generators.bases.len(), MSM_LEN);
+    assert_eq!(
+        Generators::<G>::new(b"test domain", 3).bases,
+        generators.bases[..3]
+    );
+    assert_ne!(Generators::<G>::new(b"other domain", MSM_LEN), *generators);
+    for (i, base) in generators.bases.iter().enumerate() {
+        assert!(base.mul_bigint(G::ScalarField::MODULUS).is_zero() && !base.is_zero());
+        assert!(!generators.bases[..i].contains(base) && *base != generators.blinding);
+    }
+
+    let mut rng = test_rng();
+    let a = random::<G::ScalarField>(&mut rng, MSM_LEN);
+    let b = random::<G::ScalarField>(&mut rng, 4);
+    let (r, s) = (
+        G::ScalarField::rand(&mut rng),
+        G::ScalarField::rand(&mut rng),
+    );
+
+    let ca = commit(generators, &a, r).unwrap();
+    let expected = a
+        .iter()
+        .zip(&generators.bases)
+        .fold(generators.blinding * r, |acc, (v, base)| acc + *base * v);
+    assert_eq!(ca.0, expected.into_affine());
+    assert_eq!(verify_opening(generators, &ca, &a, r), Ok(true));
+    assert_eq!(verify_opening(generators, &ca, &a, s), Ok(false));
+    assert_eq!(verify_opening(generators, &ca, &a[1..], r), Ok(false));
+
+    let cb = commit(generators, &b, s).unwrap();
+    let sum = a
+        .iter()
+        .enumerate()
+        .map(|(i, v)| *v + b.get(i).copied().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        verify_opening(generators, &(ca + cb), &sum, r + s),
+        Ok(true)
+    );
+    assert_eq!(ca + cb - cb, ca);
+    assert_eq!(
+        commit(generators, &[G::ScalarField::one(); MSM_LEN + 1], r),
+        Err(HostCallError::LengthMismatch)
+    );
+}
+
+#[test]
+fn bls12_381() {
+    check_pedersen(&sp_ark_bls12_381::pedersen::Generators::<
+        crate::bls12_381::Host,
+    >::new(b"test domain", MSM_LEN));
+}
+
+#[test]
+fn ed_on_bls12_377() {
+    check_pedersen(&sp_ark_ed_on_bls12_377::pedersen::Generators::<
+        crate::ed_on_bls12_377::Host,
+    >::new(b"test domain", MSM_LEN));
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    check_pedersen(
+        &sp_ark_ed_on_bls12_381_bandersnatch::pedersen::Generators::<
+            crate::ed_on_bls12_381_bandersnatch::Host,
+        >::new(b"test domain", MSM_LEN),
+    );
+}
@@ -0,0 +1,61 @@
+//! MSMs with host registered bases.
+
+use ark_ec::CurveGroup;
+use ark_std::{test_rng, One};
+use sp_ark_models::{
+    registry::{BasesRegistry, RegisteredBases},
+    HostCallError, TryMsm,
+};
+
+use super::{points, random, MSM_LEN};
+
+fn check_registered_bases<G: CurveGroup + TryMsm, R: BasesRegistry<G>>() {
+    let mut rng = test_rng();
+    let bases = points::<G>(&mut rng, MSM_LEN);
+    let scalars = random::<G::ScalarField>(&mut rng, MSM_LEN);
+
+    let registered = RegisteredBases::<G, R>::new(bases.clone());
+    let handle = *registered.handle().unwrap();
+    assert_eq!(R::register_bases(&bases), Ok(handle));
+    assert_ne!(R::register_bases(&bases[1..]), Ok(handle));
+
+    let expected = G::msm(&bases, &scalars).unwrap();
+    assert_eq!(R::msm_with_handle(&handle, &scalars), Ok(expected));
+    assert_eq!(registered.msm(&scalars), Ok(expected));
+    assert_eq!(
+        registered.msm(&scalars[..3]),
+        Ok(G::msm(&bases[..3], &scalars[..3]).unwrap())
+    );
+    assert_eq!(
+        R::msm_with_handle(&[0; 32], &scalars),
+        Err(HostCallError::HostRejected)
+    );
+    assert_eq!(
+        registered.msm(&[G::ScalarField::one(); MSM_LEN + 1]),
+        Err(HostCallError::LengthMismatch)
+    );
+}
+
+#[test]
+fn bls12_381() {
+    use sp_ark_bls12_381::{g1, G1Projective};
+
+    type Host = crate::bls12_381::Host;
+    check_registered_bases::<G1Projective<Host>, g1::Config<Host>>();
+}
+
+#[test]
+fn ed_on_bls12_377() {
+    use sp_ark_ed_on_bls12_377::{EdwardsConfig, EdwardsProjective};
+
+    type Host = crate::ed_on_bls12_377::Host;
+    check_registered_bases::<EdwardsProjective<Host>, EdwardsConfig<Host>>();
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    use sp_ark_ed_on_bls12_381_bandersnatch::{EdwardsConfig, EdwardsProjective};
+
+    type Host = crate::ed_on_bls12_381_bandersnatch::Host;
+    check_registered_bases::<EdwardsProjective<Host>, EdwardsConfig<Host>>();
+}
@@ -0,0 +1,131 @@
+//! Schnorr signatures over the embedded Edwards curves.
+
+use ark_ec::{
+    twisted_edwards::{Affine, TECurveConfig},
+    CurveGroup,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{test_rng, vec::Vec, One, UniformRand, Zero};
+use sha2::{Sha256, Sha512};
+use sp_ark_models::{
+    schnorr::{batch_verify, sign, verify, PublicKey, SecretKey, Signature},
+    HostCallError, TryMsm,
+};
+
+fn check_schnorr<G: CurveGroup + TryMsm>() {
+    let keys = (0u8..3)
+        .map(|i| SecretKey::<G>::from_seed(&[i]))
+        .collect::<Vec<_>>();
+    let public_keys = keys.iter().map(|sk| sk.public()).collect::<Vec<_>>();
+    let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+    let signatures = keys
+        .iter()
+        .zip(messages)
+        .map(|(sk, msg)| sign::<_, Sha512>(sk, msg))
+        .collect::<Vec<_>>();
+
+    assert_eq!(sign::<_, Sha512>(&keys[0], messages[0]), signatures[0]);
+    let mut bytes = Vec::new();
+    signatures[0].serialize_compressed(&mut bytes).unwrap();
+    assert_eq!(
+        Signature::deserialize_compressed(&bytes[..]).unwrap(),
+        signatures[0]
+    );
+
+    assert_eq!(
+        verify::<_, Sha512>(&public_keys[0], messages[0], &signatures[0]),
+        Ok(true)
+    );
+    assert_eq!(
+        verify::<_, Sha256>(&public_keys[0], messages[0], &signatures[0]),
+        Ok(false)
+    );
+    assert_eq!(
+        verify::<_, Sha512>(&public_keys[1], messages[0], &signatures[0]),
+        Ok(false)
+    );
+    assert_eq!(
+        verify::<_, Sha512>(&public_keys[0], messages[1], &signatures[0]),
+        Ok(false)
+    );
+    let signature = sign::<_, Sha256>(&keys[0], messages[0]);
+    assert_eq!(
+        verify::<_, Sha256>(&public_keys[0], messages[0], &signature),
+        Ok(true)
+    );
+
+    let mut rng = test_rng();
+    assert_eq!(
+        batch_verify::<_, Sha512, _>(&public_keys, &messages, &signatures, &mut rng),
+        Ok(true)
+    );
+    assert_eq!(
+        batch_verify::<G, Sha512, _>(&[], &[], &[], &mut rng),
+        Ok(true)
+    );
+    let mut swapped = signatures.clone();
+    swapped.swap(1, 2);
+    assert_eq!(
+        batch_verify::<_, Sha512, _>(&public_keys, &messages, &swapped, &mut rng),
+        Ok(false)
+    );
+    let wrong_keys = [public_keys[0], public_keys[2], public_keys[1]];
+    assert_eq!(
+        batch_verify::<_, Sha512, _>(&wrong_keys, &messages, &signatures, &mut rng),
+        Ok(false)
+    );
+    assert_eq!(
+        batch_verify::<_, Sha512, _>(&public_keys[1..], &messages, &signatures, &mut rng),
+        Err(HostCallError::LengthMismatch)
+    );
+}
+
+/// The identity and the point of order two of the Edwards curve `P`.
+fn torsion_points<P: TECurveConfig>() -> [Affine<P>; 2] {
+    [
+        Affine::zero(),
+        Affine::new_unchecked(Zero::zero(), -P::BaseField::one()),
+    ]
+}
+
+fn check_torsion_public_keys<G: CurveGroup + TryMsm>(torsion_points: [G::Affine; 2]) {
+    // `s·G - R` vanishes for `R = s·G`, whatever the challenge.
+    let s = G::ScalarField::rand(&mut test_rng());
+    let signature = Signature::<G> {
+        r: (G::generator() * s).into_affine(),
+        s,
+    };
+    for point in torsion_points {
+        let pk = PublicKey(point);
+        assert_eq!(verify::<_, Sha512>(&pk, b"msg", &signature), Ok(false));
+
+        let honest = SecretKey::<G>::from_seed(b"honest");
+        let public_keys = [honest.public(), pk];
+        let signatures = [sign::<_, Sha512>(&honest, b"msg"), signature];
+        assert_eq!(
+            batch_verify::<_, Sha512, _>(
+                &public_keys,
+                &[b"msg", b"msg"],
+                &signatures,
+                &mut test_rng()
+            ),
+            Ok(false)
+        );
+    }
+}
+
+#[test]
+fn ed_on_bls12_377() {
+    type Curve = sp_ark_ed_on_bls12_377::EdwardsProjective<crate::ed_on_bls12_377::Host>;
+    check_schnorr::<Curve>();
+    check_torsion_public_keys::<Curve>(torsion_points());
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    type Curve = sp_ark_ed_on_bls12_381_bandersnatch::EdwardsProjective<
+        crate::ed_on_bls12_381_bandersnatch::Host,
+    >;
+    check_schnorr::<Curve>();
+    check_torsion_public_keys::<Curve>(torsion_points());
+}
@@ -0,0 +1,88 @@
+//! MSMs with small scalars.
+
+use ark_ec::CurveGroup;
+use ark_std::{test_rng, vec::Vec};
+use sp_ark_models::small_msm::{self, SmallMsm};
+
+use super::{points, random, MSM_LEN};
+
+fn check_small_msm<G: CurveGroup, C: SmallMsm<G::Affine>>() {
+    let mut rng = test_rng();
+    for len in [0, 1, MSM_LEN, 40] {
+        let bases = points::<G>(&mut rng, len);
+        let small = random::<u64>(&mut rng, len);
+        let wide = random::<u128>(&mut rng, len);
+
+        let scalars = small.iter().map(|s| G::ScalarField::from(*s));
+        let expected = G::msm(&bases, &scalars.collect::<Vec<_>>()).unwrap();
+        assert_eq!(C::try_msm_small(&bases, &small), Ok(expected));
+        assert_eq!(C::msm_u64(&bases, &small), Ok(expected));
+
+        let scalars = wide.iter().map(|s| G::ScalarField::from(*s));
+        let expected = G::msm(&bases, &scalars.collect::<Vec<_>>()).unwrap();
+        assert_eq!(C::msm_u128(&bases, &wide), Ok(expected));
+        let wide = wide.iter().map(|s| *s as u64 as u128).collect::<Vec<_>>();
+        assert_eq!(
+            small_msm::msm::<G>(&bases, &wide),
+            G::msm(
+                &bases,
+                &wide
+                    .iter()
+                    .map(|s| G::ScalarField::from(*s))
+                    .collect::<Vec<_>>()
+            )
+            .unwrap()
+        );
+    }
+
+    let bases = [G::generator().into_affine(); 3];
+    assert_eq!(C::msm_u64(&bases, &[1, 2]), Err(2));
+    assert_eq!(C::msm_u128(&bases[..1], &[1, 2]), Err(1));
+    assert!(C::try_msm_small(&bases, &[1, 2, 3, 4]).is_err());
+}
+
+#[test]
+fn bls12_381() {
+    use sp_ark_bls12_381::{g1, g2, G1Projective, G2Projective};
+
+    type Host = crate::bls12_381::Host;
+    check_small_msm::<G1Projective<Host>, g1::Config<Host>>();
+    check_small_msm::<G2Projective<Host>, g2::Config<Host>>();
+}
+
+#[test]
+fn bls12_377() {
+    use sp_ark_bls12_377::{g1, g2, G1Projective, G2Projective};
+
+    type Host = crate::bls12_377::Host;
+    check_small_msm::<G1Projective<Host>, g1::Config<Host>>();
+    check_small_msm::<G2Projective<Host>, g2::Config<Host>>();
+}
+
+#[test]
+fn bw6_761() {
+    use sp_ark_bw6_761::{g1, g2, G1Projective, G2Projective};
+
+    type Host = crate::bw6_761::Host;
+    check_small_msm::<G1Projective<Host>, g1::Config<Host>>();
+    check_small_msm::<G2Projective<Host>, g2::Config<Host>>();
+}
+
+#[test]
+fn ed_on_bls12_377() {
+    use sp_ark_ed_on_bls12_377::{EdwardsConfig, EdwardsProjective};
+
+    type Host = crate::ed_on_bls12_377::Host;
+    check_small_msm::<EdwardsProjective<Host>, EdwardsConfig<Host>>();
+}
+
+#[test]
+fn ed_on_bls12_381_bandersnatch() {
+    use sp_ark_ed_on_bls12_381_bandersnatch::{
+        EdwardsConfig, EdwardsProjective, SWConfig, SWProjective,
+    };
+
+    type Host = crate::ed_on_bls12_381_bandersnatch::Host;
+    check_small_msm::<EdwardsProjective<Host>, EdwardsConfig<Host>>();
+    check_small_msm::<SWProjective<Host>, SWConfig<Host>>();
+}
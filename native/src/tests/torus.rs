@@ -0,0 +1,73 @@
+//! Torus compression of target group elements.
+
+use ark_ec::{
+    pairing::{Pairing, PairingOutput},
+    Group,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{test_rng, vec::Vec, UniformRand, Zero};
+use sp_ark_models::torus::{decode_host_output, Compressed, TorusCompression};
+
+/// Check the compression of the target group of `E`, whose final exponentiation
+/// host call is `final_exponentiation`.
+fn check_torus<E: Pairing>(final_exponentiation: fn(Vec<u8>) -> Result<Vec<u8>, ()>)
+where
+    E::TargetField: TorusCompression,
+{
+    let mut rng = test_rng();
+    let gt = E::pairing(E::G1::rand(&mut rng), E::G2::rand(&mut rng));
+    for value in [gt, PairingOutput::zero()] {
+        let mut bytes = Vec::new();
+        Compressed(value).serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), value.compressed_size() / 2);
+        assert_eq!(
+            Compressed::<E>::deserialize_compressed(&bytes[..]).unwrap(),
+            Compressed(value)
+        );
+    }
+
+    // Miller loop outputs are not in the cyclotomic subgroup.
+    let f = E::miller_loop(E::G1::generator(), E::G2::generator()).0;
+    let mut bytes = Vec::new();
+    assert!(Compressed(PairingOutput::<E>(f))
+        .serialize_compressed(&mut bytes)
+        .is_err());
+
+    // Torus elements outside of the target group are rejected.
+    let mut bytes = Vec::new();
+    Compressed(gt).serialize_compressed(&mut bytes).unwrap();
+    bytes[0] ^= 1;
+    assert!(Compressed::<E>::deserialize_compressed(&bytes[..]).is_err());
+    assert!(Compressed::<E>::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+
+    // The final exponentiation host call returns the compressed encoding.
+    let mut f12 = Vec::new();
+    f.serialize_uncompressed(&mut f12).unwrap();
+    let result = final_exponentiation(f12).unwrap();
+    assert_eq!(result.len(), gt.compressed_size() / 2);
+    assert_eq!(
+        decode_host_output::<E>(&result),
+        Ok(E::pairing(E::G1::generator(), E::G2::generator()))
+    );
+}
+
+#[test]
+fn bls12_381() {
+    check_torus::<sp_ark_bls12_381::Bls12_381<crate::bls12_381::Host>>(
+        crate::bls12_381::final_exponentiation,
+    );
+}
+
+#[test]
+fn bls12_377() {
+    check_torus::<sp_ark_bls12_377::Bls12_377<crate::bls12_377::Host>>(
+        crate::bls12_377::final_exponentiation,
+    );
+}
+
+#[test]
+fn bw6_761() {
+    check_torus::<sp_ark_bw6_761::BW6_761<crate::bw6_761::Host>>(
+        crate::bw6_761::final_exponentiation,
+    );
+}
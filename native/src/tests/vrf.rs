@@ -0,0 +1,32 @@
+//! Known answers of the Bandersnatch ECVRF.
+
+use ark_serialize::CanonicalSerialize;
+use sp_ark_ed_on_bls12_381_bandersnatch::vrf::{
+    prove, verify, SecretKey, OUTPUT_LEN, POINT_LEN, PROOF_LEN,
+};
+
+use super::hex;
+
+type Host = crate::ed_on_bls12_381_bandersnatch::Host;
+
+/// Public key, proof and output of the evaluation of `sample` by the key derived
+/// from `bandersnatch vrf test seed`.
+const PUBLIC_KEY: &str = "cde8d6d31d995c8ac4d76f9b8271e2f6c7c0d48d0d1a8ae09c1bf56151fee6bb";
+const PROOF: &str = "6517be8fe536e2d53453b38bfd36b2163cf86bf3f2134835ea311307cc912a254e138876ad360535c37e14c2eba39093ac6cec01d8e1b5d047710ffc7e3cc708fd2cb27433eb2b44be9c1cd3b6bd78eeb1b23f1ed1713ca312a4fad22918b416";
+const OUTPUT: &str = "60cc2d8ef7a70a64edf71bfdb21325814e98cdbff4bc5756c6b903f2ff3d5f3475bbbc722af54838ffa83af88c4928392072d48134b198f30425828562fabb9b";
+
+#[test]
+fn known_answer() {
+    let sk = SecretKey::from_seed(b"bandersnatch vrf test seed");
+    let pk = sk.public::<Host>().unwrap();
+    let proof = prove::<Host>(&sk, b"sample").unwrap();
+
+    let mut pk_bytes = [0; POINT_LEN];
+    pk.0.serialize_compressed(&mut pk_bytes[..]).unwrap();
+    assert_eq!(pk_bytes, hex::<POINT_LEN>(PUBLIC_KEY));
+    assert_eq!(proof.to_bytes(), hex::<PROOF_LEN>(PROOF));
+    assert_eq!(
+        verify(&pk, b"sample", &proof),
+        Some(hex::<OUTPUT_LEN>(OUTPUT))
+    );
+}
@@ -14,3 +14,4 @@ cargo publish -p sp-ark-ed-on-bls12-377
 cargo publish -p sp-ark-bls12-381
 cargo publish -p sp-ark-bw6-761
 cargo publish -p sp-ark-ed-on-bls12-381-bandersnatch
+cargo publish -p sp-ark-native